@@ -0,0 +1,413 @@
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::mem::take;
+use swc_common::{collections::AHashSet, util::take::Take, DUMMY_SP};
+use swc_common::errors::HANDLER;
+use swc_core::ecma::{
+    ast::*,
+    utils::{collect_decls, find_pat_ids, StmtLike},
+    visit::{as_folder, noop_visit_mut_type, noop_visit_type, Fold, FoldWith, Visit, VisitMut, VisitMutWith, VisitWith},
+};
+use swc_plugin_proxy::TransformPluginProgramMetadata;
+use swc_plugin_macro::plugin_transform;
+
+/// Options of dead-branch, the values of identifiers and member expressions
+/// like `process.env.NODE_ENV`, or of `typeof` expressions like
+/// `typeof window`, as for define-constants.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(transparent)]
+pub struct Config {
+    pub defines: BTreeMap<String, Value>,
+}
+
+/// Removes the branches of `if` statements, conditional expressions and
+/// logical expressions whose conditions are known from the constants defined
+/// by `config`, along with the imports only the removed branches used.
+pub fn dead_branch(config: Config) -> impl Fold {
+    as_folder(DeadBranch {
+        config,
+        declared: Default::default(),
+    })
+}
+
+struct DeadBranch {
+    config: Config,
+    /// Bindings of the module, which are never replaced.
+    declared: AHashSet<Id>,
+}
+
+/// A value known at build time.
+#[derive(Debug, Clone, PartialEq)]
+enum Known {
+    Undefined,
+    Null,
+    Bool(bool),
+    Num(f64),
+    Str(String),
+}
+
+impl Known {
+    fn from_json(value: &Value) -> Option<Known> {
+        match value {
+            Value::Null => Some(Known::Null),
+            Value::Bool(b) => Some(Known::Bool(*b)),
+            Value::Number(n) => n.as_f64().map(Known::Num),
+            Value::String(s) => Some(Known::Str(s.clone())),
+            // Always truthy, but never equal to anything.
+            Value::Array(..) | Value::Object(..) => None,
+        }
+    }
+
+    fn is_truthy(&self) -> bool {
+        match self {
+            Known::Undefined | Known::Null => false,
+            Known::Bool(b) => *b,
+            Known::Num(n) => *n != 0.0 && !n.is_nan(),
+            Known::Str(s) => !s.is_empty(),
+        }
+    }
+
+    fn is_nullish(&self) -> bool {
+        matches!(self, Known::Undefined | Known::Null)
+    }
+
+    /// Returns the result of `self === other`.
+    fn strict_eq(&self, other: &Known) -> bool {
+        match (self, other) {
+            (Known::Num(a), Known::Num(b)) => a == b,
+            (a, b) => a == b,
+        }
+    }
+
+    /// Returns the result of `self == other`, unless it depends on
+    /// conversions.
+    fn loose_eq(&self, other: &Known) -> Option<bool> {
+        if self.is_nullish() || other.is_nullish() {
+            return Some(self.is_nullish() && other.is_nullish());
+        }
+        (std::mem::discriminant(self) == std::mem::discriminant(other)).then(|| self.strict_eq(other))
+    }
+
+    fn into_expr(self) -> Expr {
+        match self {
+            Known::Undefined => *Expr::undefined(DUMMY_SP),
+            Known::Null => Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+            Known::Bool(b) => Expr::Lit(Lit::Bool(b.into())),
+            Known::Num(n) if n < 0.0 => Expr::Unary(UnaryExpr {
+                span: DUMMY_SP,
+                op: op!(unary, "-"),
+                arg: (-n).into(),
+            }),
+            Known::Num(n) => Expr::Lit(Lit::Num(n.into())),
+            Known::Str(s) => Expr::Lit(Lit::Str(s.into())),
+        }
+    }
+}
+
+impl DeadBranch {
+    /// Returns the value of `e` if it is known.
+    fn eval(&self, e: &Expr) -> Option<Known> {
+        match e {
+            Expr::Lit(Lit::Null(..)) => Some(Known::Null),
+            Expr::Lit(Lit::Bool(b)) => Some(Known::Bool(b.value)),
+            Expr::Lit(Lit::Num(n)) => Some(Known::Num(n.value)),
+            Expr::Lit(Lit::Str(s)) => Some(Known::Str(s.value.to_string())),
+            Expr::Ident(i) if i.sym == "undefined" && !self.declared.contains(&i.to_id()) => Some(Known::Undefined),
+            Expr::Paren(ParenExpr { expr, .. }) => self.eval(expr),
+            Expr::Unary(UnaryExpr {
+                op: op!("void"), arg, ..
+            }) if matches!(&**arg, Expr::Lit(..)) => Some(Known::Undefined),
+            Expr::Unary(UnaryExpr { op: op!("!"), arg, .. }) => Some(Known::Bool(!self.eval(arg)?.is_truthy())),
+            Expr::Unary(UnaryExpr {
+                op: op!("typeof"),
+                arg,
+                ..
+            }) => self.define(&format!("typeof {}", self.path(arg)?)),
+            Expr::Bin(BinExpr { op, left, right, .. }) => {
+                match op {
+                    op!("&&") => {
+                        let left = self.eval(left)?;
+                        return if left.is_truthy() { self.eval(right) } else { Some(left) };
+                    }
+                    op!("||") => {
+                        let left = self.eval(left)?;
+                        return if left.is_truthy() { Some(left) } else { self.eval(right) };
+                    }
+                    _ => {}
+                }
+
+                let (left, right) = (self.eval(left)?, self.eval(right)?);
+                match op {
+                    op!("===") => Some(Known::Bool(left.strict_eq(&right))),
+                    op!("!==") => Some(Known::Bool(!left.strict_eq(&right))),
+                    op!("==") => Some(Known::Bool(left.loose_eq(&right)?)),
+                    op!("!=") => Some(Known::Bool(!left.loose_eq(&right)?)),
+                    _ => None,
+                }
+            }
+            _ => self.define(&self.path(e)?),
+        }
+    }
+
+    fn define(&self, path: &str) -> Option<Known> {
+        Known::from_json(self.config.defines.get(path)?)
+    }
+
+    /// Returns the dotted path of `e`, like `process.env.NODE_ENV` for
+    /// `process.env["NODE_ENV"]`, unless its root is a binding of the module.
+    fn path(&self, e: &Expr) -> Option<String> {
+        match e {
+            Expr::Ident(i) if !self.declared.contains(&i.to_id()) => Some(i.sym.to_string()),
+            Expr::Member(MemberExpr { obj, prop, .. }) => {
+                let prop = match prop {
+                    MemberProp::Ident(i) => &i.sym,
+                    MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
+                        Expr::Lit(Lit::Str(s)) => &s.value,
+                        _ => return None,
+                    },
+                    MemberProp::PrivateName(..) => return None,
+                };
+                Some(format!("{}.{}", self.path(obj)?, prop))
+            }
+            Expr::Paren(ParenExpr { expr, .. }) => self.path(expr),
+            _ => None,
+        }
+    }
+
+    /// Folds `s`, returning whether it was an `if` statement replaced with
+    /// one of its branches.
+    fn fold_stmt(&mut self, s: &mut Stmt) -> bool {
+        s.visit_mut_children_with(self);
+
+        let Stmt::If(IfStmt { test, cons, alt, .. }) = s else {
+            return false;
+        };
+        let Some(test) = self.eval(test) else {
+            return false;
+        };
+        let (kept, dropped) = match (test.is_truthy(), alt) {
+            (true, alt) => (Some(cons.take()), alt.take()),
+            (false, alt) => (alt.take(), Some(cons.take())),
+        };
+
+        // The `var`s of the dropped branch are still declared, like
+        // `var warned;` for `if (false) { var warned = false; }`.
+        let mut hoisted = HoistedVars::default();
+        dropped.visit_with(&mut hoisted);
+        let hoisted = (!hoisted.0.is_empty()).then(|| {
+            Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                decls: hoisted
+                    .0
+                    .into_iter()
+                    .map(|id| VarDeclarator {
+                        span: DUMMY_SP,
+                        name: id.into(),
+                        init: None,
+                        definite: false,
+                    })
+                    .collect(),
+                ..Take::dummy()
+            })))
+        });
+
+        *s = match (kept, hoisted) {
+            (Some(kept), None) => *kept,
+            (None, None) => Stmt::dummy(),
+            (None, Some(hoisted)) => hoisted,
+            (Some(kept), Some(hoisted)) => match *kept {
+                Stmt::Block(mut block) => {
+                    block.stmts.insert(0, hoisted);
+                    Stmt::Block(block)
+                }
+                kept => Stmt::Block(BlockStmt {
+                    stmts: vec![hoisted, kept],
+                    ..Take::dummy()
+                }),
+            },
+        };
+        true
+    }
+
+    /// Folds the statements of `stmts`, splicing the blocks of the branches
+    /// which are kept.
+    fn fold_stmts<T: StmtLike + VisitMutWith<Self>>(&mut self, stmts: &mut Vec<T>) {
+        for item in take(stmts) {
+            let mut s = match item.try_into_stmt() {
+                Ok(s) => s,
+                Err(mut item) => {
+                    item.visit_mut_with(self);
+                    stmts.push(item);
+                    continue;
+                }
+            };
+
+            if !self.fold_stmt(&mut s) {
+                stmts.push(T::from(s));
+                continue;
+            }
+            match s {
+                Stmt::Empty(..) => {}
+                // The block scopes its declarations.
+                Stmt::Block(BlockStmt { stmts: block, .. }) if !block.iter().any(is_lexical) => {
+                    stmts.extend(block.into_iter().map(T::from));
+                }
+                s => stmts.push(T::from(s)),
+            }
+        }
+    }
+}
+
+impl VisitMut for DeadBranch {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        self.declared = collect_decls(&*m);
+
+        let mut used = UsedIdents::default();
+        m.visit_with(&mut used);
+        m.visit_mut_children_with(self);
+        remove_unused_imports(m, &used.0);
+    }
+
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        self.declared = collect_decls(&*s);
+        s.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        self.fold_stmts(items);
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        self.fold_stmts(stmts);
+    }
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        self.fold_stmt(s);
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        match e {
+            Expr::Cond(CondExpr { test, cons, alt, .. }) => {
+                let Some(test) = self.eval(test) else {
+                    return;
+                };
+                *e = if test.is_truthy() { *cons.take() } else { *alt.take() };
+            }
+            Expr::Bin(BinExpr { op, left, right, .. }) if matches!(op, op!("&&") | op!("||") | op!("??")) => {
+                let Some(known) = self.eval(left) else {
+                    return;
+                };
+                let takes_right = match op {
+                    op!("&&") => known.is_truthy(),
+                    op!("||") => !known.is_truthy(),
+                    _ => known.is_nullish(),
+                };
+                *e = if takes_right { *right.take() } else { known.into_expr() };
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Whether `s` declares bindings scoped to its block.
+fn is_lexical(s: &Stmt) -> bool {
+    match s {
+        Stmt::Decl(Decl::Var(var)) => var.kind != VarDeclKind::Var,
+        Stmt::Decl(..) => true,
+        _ => false,
+    }
+}
+
+/// Removes the specifiers of imports in `used` before folding which are not
+/// used anymore, and the imports left without specifiers.
+fn remove_unused_imports(m: &mut Module, used_before: &AHashSet<Id>) {
+    let mut used = UsedIdents::default();
+    m.visit_with(&mut used);
+
+    m.body.retain_mut(|item| {
+        let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+            return true;
+        };
+        if import.specifiers.is_empty() {
+            return true;
+        }
+
+        import.specifiers.retain(|s| {
+            let (ImportSpecifier::Named(ImportNamedSpecifier { local, .. })
+            | ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+            | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. })) = s;
+            // Imports which were already unused, like types, are left alone.
+            used.0.contains(&local.to_id()) || !used_before.contains(&local.to_id())
+        });
+        !import.specifiers.is_empty()
+    });
+}
+
+/// Collects the bindings declared by `var`s, outside of nested functions.
+#[derive(Default)]
+struct HoistedVars(Vec<Ident>);
+
+impl Visit for HoistedVars {
+    noop_visit_type!();
+
+    fn visit_var_decl(&mut self, v: &VarDecl) {
+        if v.kind == VarDeclKind::Var {
+            self.0.extend(find_pat_ids::<_, Ident>(&v.decls));
+        }
+        v.decls.iter().for_each(|d| d.init.visit_with(self));
+    }
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_class(&mut self, _: &Class) {}
+}
+
+/// Collects the identifiers used outside of imports.
+#[derive(Default)]
+struct UsedIdents(AHashSet<Id>);
+
+impl Visit for UsedIdents {
+    noop_visit_type!();
+
+    fn visit_import_decl(&mut self, _: &ImportDecl) {}
+
+    fn visit_ident(&mut self, i: &Ident) {
+        self.0.insert(i.to_id());
+    }
+}
+
+/// Reports `msg` through the diagnostics handler of swc, or logs it if there
+/// is none.
+fn error(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.err(msg));
+    } else {
+        tracing::error!("{}", msg);
+    }
+}
+
+#[plugin_transform]
+pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    let Some(json) = _metadata.get_transform_plugin_config() else {
+        error("dead-branch: missing config");
+        return program;
+    };
+    // Reported rather than panicking, which would abort the whole compilation.
+    let config = match serde_json::from_str::<Config>(&json) {
+        Ok(config) => config,
+        Err(err) => {
+            error(&format!("dead-branch: invalid config `{}`: {}", json, err));
+            return program;
+        }
+    };
+
+    program.fold_with(&mut dead_branch(config))
+}
@@ -0,0 +1,9 @@
+export const mode = process.env.NODE_ENV === 'production' ? 'prod' : 'dev';
+
+export const logger = __DEV__ && createLogger();
+
+export const storage = typeof window !== 'undefined' || memoryStorage;
+
+export const env = process.env.NODE_ENV ?? 'development';
+
+export const unknown = flag ? a : b;
@@ -0,0 +1,5 @@
+export const mode = 'prod';
+export const logger = false;
+export const storage = memoryStorage;
+export const env = "production";
+export const unknown = flag ? a : b;
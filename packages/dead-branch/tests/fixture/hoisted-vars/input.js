@@ -0,0 +1,18 @@
+if (__DEV__) {
+  var warned = false;
+}
+
+export function f() {
+  if (!warned) warned = true;
+}
+
+if (!__DEV__) {
+  prod();
+} else {
+  for (var i = 0, { length } = list; i < length; i++) {
+    var item = list[i];
+  }
+  const scoped = () => {
+    var inner;
+  };
+}
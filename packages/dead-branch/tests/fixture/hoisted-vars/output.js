@@ -0,0 +1,6 @@
+var warned;
+export function f() {
+    if (!warned) warned = true;
+}
+var i, length, item;
+prod();
@@ -0,0 +1,30 @@
+if (process.env.NODE_ENV !== 'production') {
+  console.log('dev');
+} else {
+  init();
+}
+
+if (typeof window === 'undefined') {
+  const server = true;
+  run(server);
+}
+
+if (__DEV__) {
+  debug();
+}
+
+function render() {
+  if (process.env.NODE_ENV === 'production') return prod();
+  else if (__DEV__) return dev();
+  if (isReady()) {
+    start();
+  }
+}
+
+if (process.env.NODE_ENV == null) {
+  never();
+}
+
+if (process.env.OTHER === 'x') {
+  kept();
+}
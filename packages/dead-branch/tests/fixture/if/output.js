@@ -0,0 +1,13 @@
+init();
+{
+    const server = true;
+    run(server);
+}function render() {
+    return prod();
+    if (isReady()) {
+        start();
+    }
+}
+if (process.env.OTHER === 'x') {
+    kept();
+}
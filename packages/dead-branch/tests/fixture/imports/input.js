@@ -0,0 +1,13 @@
+import { DevTools, devLog } from './devtools';
+import Inspector from './inspector';
+import { App } from './app';
+import { Props } from './types';
+
+export function Root() {
+  if (__DEV__) {
+    devLog('render');
+  }
+  return process.env.NODE_ENV !== 'production' ? <DevTools><App /></DevTools> : <App />;
+}
+
+export const inspect = __DEV__ ? Inspector : null;
@@ -0,0 +1,6 @@
+import { App } from './app';
+import { Props } from './types';
+export function Root() {
+    return <App/>;
+}
+export const inspect = null;
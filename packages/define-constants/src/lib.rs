@@ -0,0 +1,175 @@
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use swc_common::{collections::AHashSet, DUMMY_SP};
+use swc_common::errors::HANDLER;
+use swc_core::ecma::{
+    ast::*,
+    utils::collect_decls,
+    visit::{as_folder, noop_visit_mut_type, Fold, FoldWith, VisitMut, VisitMutWith},
+};
+use swc_plugin_proxy::TransformPluginProgramMetadata;
+use swc_plugin_macro::plugin_transform;
+
+/// Options of define-constants, the values of identifiers and member
+/// expressions like `__VERSION__` and `process.env.STAGE`, or of `typeof`
+/// expressions like `typeof window`.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(transparent)]
+pub struct Config {
+    pub defines: BTreeMap<String, Value>,
+}
+
+/// Replaces the expressions defined by `config` with their values, like the
+/// `DefinePlugin` of webpack, except that values are JSON rather than code.
+pub fn define_constants(config: Config) -> impl Fold {
+    as_folder(DefineConstants {
+        config,
+        declared: Default::default(),
+    })
+}
+
+struct DefineConstants {
+    config: Config,
+    /// Bindings of the module, which are never replaced.
+    declared: AHashSet<Id>,
+}
+
+impl DefineConstants {
+    /// Returns the value defined for `e`.
+    fn value(&self, e: &Expr) -> Option<&Value> {
+        match e {
+            Expr::Unary(UnaryExpr {
+                op: op!("typeof"),
+                arg,
+                ..
+            }) => self.config.defines.get(&format!("typeof {}", self.path(arg)?)),
+            _ => self.config.defines.get(&self.path(e)?),
+        }
+    }
+
+    /// Returns the dotted path of `e`, like `process.env.STAGE` for
+    /// `process.env["STAGE"]`, unless its root is a binding of the module.
+    fn path(&self, e: &Expr) -> Option<String> {
+        match e {
+            Expr::Ident(i) if !self.declared.contains(&i.to_id()) => Some(i.sym.to_string()),
+            Expr::Member(MemberExpr { obj, prop, .. }) => {
+                let prop = match prop {
+                    MemberProp::Ident(i) => &i.sym,
+                    MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
+                        Expr::Lit(Lit::Str(s)) => &s.value,
+                        _ => return None,
+                    },
+                    MemberProp::PrivateName(..) => return None,
+                };
+                Some(format!("{}.{}", self.path(obj)?, prop))
+            }
+            Expr::Paren(ParenExpr { expr, .. }) => self.path(expr),
+            _ => None,
+        }
+    }
+}
+
+impl VisitMut for DefineConstants {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        self.declared = collect_decls(&*m);
+        m.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        self.declared = collect_decls(&*s);
+        s.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        match self.value(e) {
+            Some(value) => *e = json_to_expr(value),
+            None => e.visit_mut_children_with(self),
+        }
+    }
+
+    // `{ __VERSION__ }`
+    fn visit_mut_prop(&mut self, p: &mut Prop) {
+        if let Prop::Shorthand(i) = p {
+            if let Some(value) = self.value(&Expr::Ident(i.clone())) {
+                *p = Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(i.clone().into()),
+                    value: Box::new(json_to_expr(value)),
+                });
+                return;
+            }
+        }
+
+        p.visit_mut_children_with(self);
+    }
+}
+
+/// Creates the literal of `value`.
+fn json_to_expr(value: &Value) -> Expr {
+    match value {
+        Value::Null => Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+        Value::Bool(b) => Expr::Lit(Lit::Bool((*b).into())),
+        Value::Number(n) => {
+            let n = n.as_f64().unwrap_or(f64::NAN);
+            if n < 0.0 {
+                Expr::Unary(UnaryExpr {
+                    span: DUMMY_SP,
+                    op: op!(unary, "-"),
+                    arg: (-n).into(),
+                })
+            } else {
+                Expr::Lit(Lit::Num(n.into()))
+            }
+        }
+        Value::String(s) => Expr::Lit(Lit::Str(s.as_str().into())),
+        Value::Array(values) => Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: values
+                .iter()
+                .map(|v| Some(Box::new(json_to_expr(v)).into()))
+                .collect(),
+        }),
+        Value::Object(props) => Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props: props
+                .iter()
+                .map(|(key, v)| {
+                    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: PropName::Str(key.as_str().into()),
+                        value: Box::new(json_to_expr(v)),
+                    })))
+                })
+                .collect(),
+        }),
+    }
+}
+
+/// Reports `msg` through the diagnostics handler of swc, or logs it if there
+/// is none.
+fn error(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.err(msg));
+    } else {
+        tracing::error!("{}", msg);
+    }
+}
+
+#[plugin_transform]
+pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    let Some(json) = _metadata.get_transform_plugin_config() else {
+        error("define-constants: missing config");
+        return program;
+    };
+    // Reported rather than panicking, which would abort the whole compilation.
+    let config = match serde_json::from_str::<Config>(&json) {
+        Ok(config) => config,
+        Err(err) => {
+            error(&format!("define-constants: invalid config `{}`: {}", json, err));
+            return program;
+        }
+    };
+
+    program.fold_with(&mut define_constants(config))
+}
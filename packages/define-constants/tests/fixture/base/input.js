@@ -0,0 +1,7 @@
+export const meta = { __VERSION__, stage: process.env.STAGE, retries: process.env['RETRIES'] };
+
+if (__DEV__) {
+  console.log(FEATURES.search, LOCALES, OFFSET);
+}
+
+console.log(process.env.HOME, process.env);
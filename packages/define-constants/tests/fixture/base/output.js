@@ -0,0 +1,15 @@
+export const meta = {
+    __VERSION__: "1.2.0",
+    stage: "prod",
+    retries: 3
+};
+if (false) {
+    console.log({
+        "beta": null,
+        "search": true
+    }.search, [
+        "en",
+        "zh"
+    ], -1);
+}
+console.log(process.env.HOME, process.env);
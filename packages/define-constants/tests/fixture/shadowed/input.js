@@ -0,0 +1,5 @@
+import process from './process';
+
+const __VERSION__ = read();
+
+console.log(process.env.STAGE, __VERSION__);
@@ -0,0 +1,3 @@
+import process from './process';
+const __VERSION__ = read();
+console.log(process.env.STAGE, __VERSION__);
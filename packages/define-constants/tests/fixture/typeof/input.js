@@ -0,0 +1,3 @@
+export const isServer = typeof window === 'undefined';
+export const load = typeof require === 'function' ? require : null;
+export const hasDocument = typeof document !== 'undefined';
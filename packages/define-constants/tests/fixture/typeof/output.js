@@ -0,0 +1,3 @@
+export const isServer = "undefined" === 'undefined';
+export const load = "function" === 'function' ? require : null;
+export const hasDocument = typeof document !== 'undefined';
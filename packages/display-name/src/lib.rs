@@ -0,0 +1,208 @@
+use serde::Deserialize;
+use std::mem::take;
+use swc_common::{collections::AHashSet, DUMMY_SP};
+use swc_common::errors::HANDLER;
+use swc_core::ecma::{
+    ast::*,
+    atoms::Atom,
+    utils::{quote_ident, ExprFactory},
+    visit::{as_folder, noop_visit_mut_type, Fold, FoldWith, VisitMut},
+};
+use swc_plugin_proxy::TransformPluginProgramMetadata;
+use swc_plugin_macro::plugin_transform;
+
+/// Functions of `react` creating components whose name can't be inferred.
+const REACT_FACTORIES: [&str; 3] = ["memo", "forwardRef", "lazy"];
+
+/// Options of display-name.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Also name the components declared as arrow functions, like
+    /// `const Button = () => <button />`, which keep their name only until
+    /// minification. Defaults to `true`.
+    #[serde(default)]
+    pub arrow_functions: Option<bool>,
+
+    /// Other functions creating components, like `observer`.
+    #[serde(default)]
+    pub factories: Vec<String>,
+}
+
+/// Assigns `displayName` to the top-level components created by `memo`,
+/// `forwardRef` and `lazy` of React, and declared as arrow functions, after
+/// the name of their binding, for React DevTools.
+pub fn display_name(config: Config) -> impl Fold {
+    as_folder(DisplayName {
+        config,
+        react: Default::default(),
+        factories: Default::default(),
+    })
+}
+
+struct DisplayName {
+    config: Config,
+    /// Default and namespace imports of `react`.
+    react: AHashSet<Id>,
+    /// Imports of [REACT_FACTORIES].
+    factories: AHashSet<Id>,
+}
+
+impl DisplayName {
+    /// Collects the imports of React from `items`.
+    fn collect_imports(&mut self, items: &[ModuleItem]) {
+        for item in items {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+                continue;
+            };
+            if &*import.src.value != "react" {
+                continue;
+            }
+
+            for s in &import.specifiers {
+                match s {
+                    ImportSpecifier::Named(ImportNamedSpecifier { local, imported, .. }) => {
+                        let imported = imported.as_ref().map_or(&local.sym, |i| i.atom());
+                        if REACT_FACTORIES.contains(&&**imported) {
+                            self.factories.insert(local.to_id());
+                        }
+                    }
+                    ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                    | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                        self.react.insert(local.to_id());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Whether `e` creates a component without a name of its own.
+    fn is_component(&self, e: &Expr) -> bool {
+        match e {
+            Expr::Paren(ParenExpr { expr, .. }) => self.is_component(expr),
+            Expr::Arrow(..) => self.config.arrow_functions.unwrap_or(true),
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                ..
+            }) => match &**callee {
+                Expr::Ident(i) => self.factories.contains(&i.to_id()) || self.config.factories.iter().any(|f| f == &*i.sym),
+                Expr::Member(MemberExpr {
+                    obj,
+                    prop: MemberProp::Ident(prop),
+                    ..
+                }) => {
+                    REACT_FACTORIES.contains(&&*prop.sym)
+                        && matches!(&**obj, Expr::Ident(i) if self.react.contains(&i.to_id()))
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Returns the names of the components declared by `item`.
+    fn components(&self, item: &ModuleItem) -> Vec<Atom> {
+        let var = match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var)))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Var(var), ..
+            })) => var,
+            _ => return vec![],
+        };
+
+        var.decls
+            .iter()
+            .filter_map(|d| match (&d.name, &d.init) {
+                // Components are capitalized, unlike the hooks and helpers
+                // declared as arrow functions.
+                (Pat::Ident(name), Some(init))
+                    if name.sym.starts_with(|c: char| c.is_ascii_uppercase()) && self.is_component(init) =>
+                {
+                    Some(name.sym.clone())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl VisitMut for DisplayName {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        self.collect_imports(items);
+        let named: AHashSet<Atom> = items.iter().filter_map(display_name_assign).collect();
+
+        for item in take(items) {
+            let components = self.components(&item);
+            items.push(item);
+
+            for name in components {
+                if named.contains(&name) {
+                    continue;
+                }
+                let assign = AssignExpr {
+                    span: DUMMY_SP,
+                    op: op!("="),
+                    left: quote_ident!(name.clone())
+                        .make_member(quote_ident!("displayName"))
+                        .into(),
+                    right: Box::new(Expr::Lit(Lit::Str(name.into()))),
+                };
+                items.push(ModuleItem::Stmt(assign.into_stmt()));
+            }
+        }
+    }
+}
+
+/// Returns the name of the component whose `displayName` is assigned by
+/// `item`, like `Button` of `Button.displayName = "Button"`.
+fn display_name_assign(item: &ModuleItem) -> Option<Atom> {
+    let ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) = item else {
+        return None;
+    };
+    let Expr::Assign(AssignExpr {
+        left: AssignTarget::Simple(SimpleAssignTarget::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        })),
+        ..
+    }) = &**expr
+    else {
+        return None;
+    };
+
+    match &**obj {
+        Expr::Ident(obj) if prop.sym == "displayName" => Some(obj.sym.clone()),
+        _ => None,
+    }
+}
+
+/// Reports `msg` through the diagnostics handler of swc, or logs it if there
+/// is none.
+fn error(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.err(msg));
+    } else {
+        tracing::error!("{}", msg);
+    }
+}
+
+#[plugin_transform]
+pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    let Some(json) = _metadata.get_transform_plugin_config() else {
+        error("display-name: missing config");
+        return program;
+    };
+    // Reported rather than panicking, which would abort the whole compilation.
+    let config = match serde_json::from_str::<Config>(&json) {
+        Ok(config) => config,
+        Err(err) => {
+            error(&format!("display-name: invalid config `{}`: {}", json, err));
+            return program;
+        }
+    };
+
+    program.fold_with(&mut display_name(config))
+}
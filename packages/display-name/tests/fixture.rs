@@ -0,0 +1,29 @@
+use std::{fs, path::PathBuf};
+use swc_core::{
+  ecma::parser::{EsSyntax, Syntax},
+  ecma::transforms::testing::{test_fixture, FixtureTestConfig},
+};
+use testing::fixture;
+use swc_plugin_display_name::{display_name, Config};
+
+#[fixture("tests/fixture/**/input.js")]
+fn fixture(input: PathBuf) {
+  let parent = input.parent().unwrap();
+  let output = parent.join("output.js");
+  let options = fs::read_to_string(parent.join("options.json")).unwrap();
+
+  test_fixture(
+    Syntax::Es(EsSyntax {
+      jsx: true,
+      ..Default::default()
+    }),
+    &|_t| {
+      display_name(serde_json::from_str::<Config>(&options).unwrap())
+    },
+    &input,
+    &output,
+    FixtureTestConfig {
+      ..Default::default()
+    },
+  );
+}
@@ -0,0 +1,24 @@
+import React, { memo, forwardRef as fwd } from 'react';
+
+export const Button = memo(function ({ children }) {
+  return <button>{children}</button>;
+});
+
+const Input = fwd((props, ref) => <input ref={ref} {...props} />);
+
+export const Card = () => <div className="card" />;
+
+const Page = React.lazy(() => import('./Page'));
+
+const Named = React.memo(() => <span />);
+Named.displayName = 'Custom';
+
+const useToggle = () => useState(false);
+
+const helper = memo(() => null);
+
+function Plain() {
+  return null;
+}
+
+export { Input, Page };
@@ -0,0 +1,19 @@
+import React, { memo, forwardRef as fwd } from 'react';
+export const Button = memo(function({ children }) {
+    return <button>{children}</button>;
+});
+Button.displayName = "Button";
+const Input = fwd((props, ref)=><input ref={ref} {...props}/>);
+Input.displayName = "Input";
+export const Card = ()=><div className="card"/>;
+Card.displayName = "Card";
+const Page = React.lazy(()=>import('./Page'));
+Page.displayName = "Page";
+const Named = React.memo(()=><span/>);
+Named.displayName = 'Custom';
+const useToggle = ()=>useState(false);
+const helper = memo(()=>null);
+function Plain() {
+    return null;
+}
+export { Input, Page };
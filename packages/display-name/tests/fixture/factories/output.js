@@ -0,0 +1,3 @@
+import { observer } from 'mobx-react-lite';
+export const Counter = observer(()=><span>{store.count}</span>);
+Counter.displayName = "Counter";
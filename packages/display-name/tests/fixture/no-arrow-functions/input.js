@@ -0,0 +1,5 @@
+import { memo } from 'react';
+
+export const Card = () => <div className="card" />;
+
+export const List = memo(() => <ul />);
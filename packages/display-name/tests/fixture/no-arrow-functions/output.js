@@ -0,0 +1,4 @@
+import { memo } from 'react';
+export const Card = ()=><div className="card"/>;
+export const List = memo(()=><ul/>);
+List.displayName = "List";
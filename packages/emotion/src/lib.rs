@@ -0,0 +1,525 @@
+use serde::Deserialize;
+use std::path::Path;
+use swc_common::{
+    collections::AHashSet, errors::SourceMapper, plugin::metadata::TransformPluginMetadataContextKind,
+    sync::Lrc, util::take::Take, Span, DUMMY_SP,
+};
+use swc_common::errors::HANDLER;
+use swc_core::ecma::{
+    ast::*,
+    atoms::Atom,
+    utils::{quote_ident, ExprFactory},
+    visit::{as_folder, noop_visit_mut_type, Fold, FoldWith, VisitMut, VisitMutWith},
+};
+use swc_plugin_proxy::TransformPluginProgramMetadata;
+use swc_plugin_macro::plugin_transform;
+
+/// Options of emotion, like those of `@emotion/babel-plugin`.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Label the styles with the name of their binding, so class names read
+    /// like `css-1x2y3z-title`. Defaults to `true`.
+    #[serde(default)]
+    pub auto_label: Option<bool>,
+
+    /// Format of the labels, in which `[local]` is the name of the binding,
+    /// `[filename]` the name of the file and `[dirname]` the name of its
+    /// directory. Defaults to `[local]`.
+    #[serde(default)]
+    pub label_format: Option<String>,
+
+    /// Append a source map comment to the styles, pointing at their location
+    /// in the file, for the browser devtools.
+    #[serde(default)]
+    pub source_map: bool,
+
+    /// Drop the comments and the unneeded whitespace of the styles of
+    /// template literals. Defaults to `true`.
+    #[serde(default)]
+    pub minify: Option<bool>,
+}
+
+/// Labels, minifies and maps the styles passed to `css` and `styled` of
+/// emotion in the file named `file_name`. `source_map` locates them for
+/// [Config::source_map].
+pub fn emotion(config: Config, file_name: &str, source_map: Option<Lrc<dyn SourceMapper>>) -> impl Fold {
+    let path = Path::new(file_name);
+    let name_of = |p: Option<&Path>| {
+        p.and_then(|p| p.file_stem())
+            .and_then(|s| s.to_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+
+    as_folder(Emotion {
+        file_stem: name_of(Some(path)),
+        dir_name: name_of(path.parent()),
+        file_name: file_name.to_string(),
+        file_hash: base36(fnv1a(file_name.as_bytes())),
+        config,
+        source_map,
+        css: Default::default(),
+        styled: Default::default(),
+        name: None,
+        next_id: 0,
+    })
+}
+
+struct Emotion {
+    config: Config,
+    source_map: Option<Lrc<dyn SourceMapper>>,
+    file_name: String,
+    file_stem: String,
+    dir_name: String,
+    file_hash: String,
+    /// Imports of `css`.
+    css: AHashSet<Id>,
+    /// Default imports of `@emotion/styled`.
+    styled: AHashSet<Id>,
+    /// Name of the binding being initialized.
+    name: Option<Atom>,
+    next_id: usize,
+}
+
+impl Emotion {
+    /// Returns the label of the styles being visited.
+    fn label(&self) -> Option<String> {
+        if !self.config.auto_label.unwrap_or(true) {
+            return None;
+        }
+        let name = self.name.as_ref()?;
+        let format = self.config.label_format.as_deref().unwrap_or("[local]");
+
+        Some(
+            format
+                .replace("[local]", name)
+                .replace("[filename]", &self.file_stem)
+                .replace("[dirname]", &self.dir_name),
+        )
+    }
+
+    /// Returns the source map comment locating the styles at `span`.
+    fn source_map_comment(&self, span: Span) -> Option<String> {
+        if !self.config.source_map || span.is_dummy() {
+            return None;
+        }
+        let loc = self.source_map.as_ref()?.lookup_char_pos(span.lo);
+
+        let mut mappings = String::new();
+        for n in [0, 0, loc.line as i64 - 1, loc.col.0 as i64] {
+            vlq(&mut mappings, n);
+        }
+        let map = serde_json::json!({
+            "version": 3,
+            "sources": [self.file_name],
+            "names": [],
+            "mappings": mappings,
+        });
+
+        Some(format!(
+            "/*# sourceMappingURL=data:application/json;charset=utf-8;base64,{} */",
+            base64(map.to_string().as_bytes())
+        ))
+    }
+
+    /// Returns what to append to the styles at `span`, like `label:title;`.
+    fn suffix(&self, span: Span) -> String {
+        let mut suffix = String::new();
+        if let Some(label) = self.label() {
+            suffix.push_str(&format!("label:{};", label));
+        }
+        if let Some(comment) = self.source_map_comment(span) {
+            suffix.push_str(&comment);
+        }
+        suffix
+    }
+
+    /// Minifies the template `tpl` and appends the suffix of its styles.
+    fn process_tpl(&self, tpl: &mut Tpl, suffix: String) {
+        if self.config.minify.unwrap_or(true) {
+            let last = tpl.quasis.len() - 1;
+            for (i, q) in tpl.quasis.iter_mut().enumerate() {
+                let mut css = minify(&q.raw);
+                if i == 0 {
+                    css = css.trim_start().to_string();
+                }
+                if i == last {
+                    css = css.trim_end().to_string();
+                }
+                set_quasi(q, css);
+            }
+        }
+
+        if !suffix.is_empty() {
+            let q = tpl.quasis.last_mut().unwrap();
+            let mut css = q.raw.trim_end().to_string();
+            if !css.is_empty() && !css.ends_with(';') && !css.ends_with('}') {
+                css.push(';');
+            }
+            css.push_str(&suffix);
+            set_quasi(q, css);
+        }
+    }
+
+    /// Whether `callee` is `css`.
+    fn is_css(&self, callee: &Expr) -> bool {
+        matches!(callee, Expr::Ident(i) if self.css.contains(&i.to_id()))
+    }
+
+    /// Turns the `styled.div` or `styled(Link)` factory `e` into
+    /// `styled("div", { target, label })`.
+    fn configure_styled(&mut self, e: &mut Box<Expr>) {
+        let is_styled = |e: &Expr| matches!(e, Expr::Ident(i) if self.styled.contains(&i.to_id()));
+        let target = format!("e{}{}", self.file_hash, self.next_id);
+
+        let mut options = vec![str_prop("target", target)];
+        if let Some(label) = self.label() {
+            options.push(str_prop("label", label));
+        }
+        let options = ObjectLit {
+            span: DUMMY_SP,
+            props: options,
+        }
+        .as_arg();
+
+        match &mut **e {
+            // styled.div
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(tag),
+                ..
+            }) if is_styled(obj) => {
+                let tag = Expr::Lit(Lit::Str(tag.sym.clone().into())).as_arg();
+                *e = Box::new(obj.take().as_call(DUMMY_SP, vec![tag, options]));
+            }
+            // styled(Link) and styled(Link, { shouldForwardProp })
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                args,
+                ..
+            }) if is_styled(callee) && args.len() == 1 => args.push(options),
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                args,
+                ..
+            }) if is_styled(callee) => match args.get_mut(1) {
+                Some(ExprOrSpread { spread: None, expr }) => match &mut **expr {
+                    Expr::Object(existing) if !existing.props.iter().any(is_target) => {
+                        let Expr::Object(options) = *options.expr else {
+                            unreachable!()
+                        };
+                        existing.props.extend(options.props);
+                    }
+                    _ => return,
+                },
+                _ => return,
+            },
+            _ => return,
+        }
+        self.next_id += 1;
+    }
+}
+
+impl VisitMut for Emotion {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        for item in &m.body {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+                continue;
+            };
+            let src = &*import.src.value;
+
+            for s in &import.specifiers {
+                match s {
+                    ImportSpecifier::Named(ImportNamedSpecifier { local, imported, .. })
+                        if src == "@emotion/react" || src == "@emotion/css" =>
+                    {
+                        if &**imported.as_ref().map_or(&local.sym, |i| i.atom()) == "css" {
+                            self.css.insert(local.to_id());
+                        }
+                    }
+                    ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) if src == "@emotion/styled" => {
+                        self.styled.insert(local.to_id());
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if !self.css.is_empty() || !self.styled.is_empty() {
+            m.visit_mut_children_with(self);
+        }
+    }
+
+    fn visit_mut_var_declarator(&mut self, d: &mut VarDeclarator) {
+        let old = self.name.take();
+        if let Pat::Ident(name) = &d.name {
+            self.name = Some(name.sym.clone());
+        }
+        d.visit_mut_children_with(self);
+        self.name = old;
+    }
+
+    fn visit_mut_key_value_prop(&mut self, p: &mut KeyValueProp) {
+        let old = self.name.take();
+        if let PropName::Ident(key) = &p.key {
+            self.name = Some(key.sym.clone());
+        }
+        p.visit_mut_children_with(self);
+        self.name = old;
+    }
+
+    fn visit_mut_function(&mut self, f: &mut Function) {
+        let old = self.name.take();
+        f.visit_mut_children_with(self);
+        self.name = old;
+    }
+
+    fn visit_mut_arrow_expr(&mut self, f: &mut ArrowExpr) {
+        let old = self.name.take();
+        f.visit_mut_children_with(self);
+        self.name = old;
+    }
+
+    // css`color: red;` and styled.div`color: red;`
+    fn visit_mut_tagged_tpl(&mut self, t: &mut TaggedTpl) {
+        t.visit_mut_children_with(self);
+
+        if self.is_css(&t.tag) {
+            let suffix = self.suffix(t.span);
+            self.process_tpl(&mut t.tpl, suffix);
+        } else {
+            let next_id = self.next_id;
+            self.configure_styled(&mut t.tag);
+            if self.next_id != next_id {
+                // Labeled by the options of `styled`.
+                let suffix = self.source_map_comment(t.span).unwrap_or_default();
+                self.process_tpl(&mut t.tpl, suffix);
+            }
+        }
+    }
+
+    // css({ color: 'red' }) and styled.div({ color: 'red' })
+    fn visit_mut_call_expr(&mut self, c: &mut CallExpr) {
+        c.visit_mut_children_with(self);
+
+        let Callee::Expr(callee) = &mut c.callee else {
+            return;
+        };
+        if self.is_css(callee) {
+            let suffix = self.suffix(c.span);
+            if !suffix.is_empty() {
+                c.args.push(Expr::Lit(Lit::Str(suffix.into())).as_arg());
+            }
+        } else if matches!(&**callee, Expr::Member(..) | Expr::Call(..)) {
+            let next_id = self.next_id;
+            self.configure_styled(callee);
+            if self.next_id != next_id {
+                if let Some(comment) = self.source_map_comment(c.span) {
+                    c.args.push(Expr::Lit(Lit::Str(comment.into())).as_arg());
+                }
+            }
+        }
+    }
+}
+
+fn str_prop(key: &str, value: String) -> PropOrSpread {
+    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+        key: PropName::Ident(quote_ident!(key)),
+        value: Box::new(Expr::Lit(Lit::Str(value.into()))),
+    })))
+}
+
+/// Whether `p` is the `target` option of `styled`.
+fn is_target(p: &PropOrSpread) -> bool {
+    matches!(
+        p,
+        PropOrSpread::Prop(p) if matches!(
+            &**p,
+            Prop::KeyValue(KeyValueProp { key: PropName::Ident(k), .. }) if k.sym == "target"
+        )
+    )
+}
+
+fn set_quasi(q: &mut TplElement, css: String) {
+    q.cooked = Some(css.clone().into());
+    q.raw = css.into();
+}
+
+/// Drops the comments and the unneeded whitespace of `css`.
+///
+/// Strings and `url(...)` are copied verbatim, and the whitespace before a
+/// `:` is only dropped inside declarations, since `& :first-child` differs
+/// from `&:first-child`.
+fn minify(css: &str) -> String {
+    let chars: Vec<char> = css.chars().collect();
+    let mut out = String::with_capacity(css.len());
+    let mut space = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i = (i + 2..chars.len().saturating_sub(1))
+                .find(|&j| chars[j] == '*' && chars[j + 1] == '/')
+                .map_or(chars.len(), |j| j + 2);
+            continue;
+        }
+        if c.is_whitespace() {
+            space = true;
+            i += 1;
+            continue;
+        }
+        if space {
+            // Spaces are only needed between words, like `1px solid`.
+            let needed = if out.is_empty() {
+                // Kept at the start of the quasis following interpolations.
+                true
+            } else if out.ends_with(['{', '}', ';', ',', ':']) {
+                false
+            } else if c == ':' {
+                !in_declaration(&chars[i..])
+            } else {
+                !matches!(c, '{' | '}' | ';' | ',')
+            };
+            if needed {
+                out.push(' ');
+            }
+            space = false;
+        }
+        let verbatim_end = match c {
+            '"' | '\'' => Some(string_end(&chars, i)),
+            'u' if chars[i..].starts_with(&['u', 'r', 'l', '(']) => Some(
+                (i + 4..chars.len())
+                    .find(|&j| chars[j] == ')')
+                    .map_or(chars.len(), |j| j + 1),
+            ),
+            _ => None,
+        };
+        match verbatim_end {
+            Some(end) => {
+                out.extend(&chars[i..end]);
+                i = end;
+            }
+            None => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+    if space && !out.ends_with(['{', '}', ';', ',', ':']) {
+        out.push(' ');
+    }
+    out
+}
+
+/// The index after the string starting at `start`, or the end of `chars` if
+/// it isn't closed.
+fn string_end(chars: &[char], start: usize) -> usize {
+    let quote = chars[start];
+    let mut i = start + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            c if c == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+    chars.len()
+}
+
+/// Whether the `:` starting `rest` ends a property name rather than being
+/// part of a selector, i.e. whether the rule ends before a block opens.
+fn in_declaration(rest: &[char]) -> bool {
+    rest.iter()
+        .find(|c| matches!(c, '{' | '}' | ';'))
+        .is_some_and(|c| *c != '{')
+}
+
+/// The 32-bit FNV-1a hash of `bytes`.
+fn fnv1a(bytes: &[u8]) -> u32 {
+    bytes
+        .iter()
+        .fold(0x811c9dc5, |hash, b| (hash ^ u32::from(*b)).wrapping_mul(0x01000193))
+}
+
+fn base36(mut n: u32) -> String {
+    let mut digits = vec![];
+    loop {
+        digits.push(std::char::from_digit(n % 36, 36).unwrap());
+        n /= 36;
+        if n == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+/// Appends the base64 VLQ of `n` to `out`, as in the `mappings` of source
+/// maps.
+fn vlq(out: &mut String, n: i64) {
+    const DIGITS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut v = if n < 0 { ((-n) << 1) | 1 } else { n << 1 };
+    loop {
+        let mut digit = v & 0b11111;
+        v >>= 5;
+        if v > 0 {
+            digit |= 0b100000;
+        }
+        out.push(DIGITS[digit as usize] as char);
+        if v == 0 {
+            break;
+        }
+    }
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const DIGITS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, b)| n | (u32::from(*b) << (16 - 8 * i)));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(DIGITS[(n >> (18 - 6 * i) & 0b111111) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// Reports `msg` through the diagnostics handler of swc, or logs it if there
+/// is none.
+fn error(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.err(msg));
+    } else {
+        tracing::error!("{}", msg);
+    }
+}
+
+#[plugin_transform]
+pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    let Some(json) = _metadata.get_transform_plugin_config() else {
+        error("emotion: missing config");
+        return program;
+    };
+    // Reported rather than panicking, which would abort the whole compilation.
+    let config = match serde_json::from_str::<Config>(&json) {
+        Ok(config) => config,
+        Err(err) => {
+            error(&format!("emotion: invalid config `{}`: {}", json, err));
+            return program;
+        }
+    };
+    let file_name = _metadata
+        .get_context(&TransformPluginMetadataContextKind::Filename)
+        .unwrap_or_default();
+    let source_map: Lrc<dyn SourceMapper> = Lrc::new(_metadata.source_map);
+
+    program.fold_with(&mut emotion(config, &file_name, Some(source_map)))
+}
@@ -0,0 +1,36 @@
+use std::{fs, path::PathBuf};
+use swc_core::{
+  ecma::parser::{EsSyntax, Syntax},
+  ecma::transforms::testing::{test_fixture, FixtureTestConfig},
+};
+use testing::fixture;
+use swc_plugin_emotion::{emotion, Config};
+
+#[fixture("tests/fixture/**/input.js")]
+fn fixture(input: PathBuf) {
+  let parent = input.parent().unwrap();
+  let output = parent.join("output.js");
+  let options = fs::read_to_string(parent.join("options.json")).unwrap();
+  // Named after the fixture rather than its absolute path, so the targets
+  // and source maps are the same on every machine.
+  let file_name = format!("src/{}/Card.js", parent.file_name().unwrap().to_str().unwrap());
+
+  test_fixture(
+    Syntax::Es(EsSyntax {
+      jsx: true,
+      ..Default::default()
+    }),
+    &|t| {
+      emotion(
+        serde_json::from_str::<Config>(&options).unwrap(),
+        &file_name,
+        Some(t.cm.clone()),
+      )
+    },
+    &input,
+    &output,
+    FixtureTestConfig {
+      ..Default::default()
+    },
+  );
+}
@@ -0,0 +1,5 @@
+import { css } from '@emotion/react';
+
+const title = css`
+  color: hotpink;
+`;
@@ -0,0 +1,3 @@
+import { css } from '@emotion/react';
+const title = css`
+  color: hotpink;label:label-format-Card--title;`;
@@ -0,0 +1,16 @@
+import { css } from '@emotion/react';
+import { css as cx } from '@emotion/css';
+
+const title = css`
+  color: hotpink;
+`;
+
+const button = cx({ padding: 8 });
+
+const styles = {
+  header: css`font-size: 2em`,
+};
+
+export function render() {
+  return css`margin: 0`;
+}
@@ -0,0 +1,12 @@
+import { css } from '@emotion/react';
+import { css as cx } from '@emotion/css';
+const title = css`color:hotpink;label:title;`;
+const button = cx({
+    padding: 8
+}, "label:button;");
+const styles = {
+    header: css`font-size:2em;label:header;`
+};
+export function render() {
+    return css`margin:0`;
+}
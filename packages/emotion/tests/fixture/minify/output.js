@@ -0,0 +1,2 @@
+import { css } from '@emotion/react';
+const card = css`display:flex;border:1px solid ${color};&:hover{color:red;}& :first-child{content:"a  :  b";background:url( "/a  b.png" ) no-repeat;}@media (min-width:420px){font-size:50px;}`;
@@ -0,0 +1,6 @@
+import { css } from '@emotion/react';
+import styled from '@emotion/styled';
+
+const title = css`color: hotpink;`;
+
+const Button = styled.button`padding: 4px;`;
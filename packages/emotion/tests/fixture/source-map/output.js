@@ -0,0 +1,7 @@
+import { css } from '@emotion/react';
+import styled from '@emotion/styled';
+const title = css`color:hotpink;label:title;/*# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJtYXBwaW5ncyI6IkFBR2MiLCJuYW1lcyI6W10sInNvdXJjZXMiOlsic3JjL3NvdXJjZS1tYXAvQ2FyZC5qcyJdLCJ2ZXJzaW9uIjozfQ== */`;
+const Button = styled("button", {
+    target: "ej9ahjk0",
+    label: "Button"
+})`padding:4px;/*# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJtYXBwaW5ncyI6IkFBS2UiLCJuYW1lcyI6W10sInNvdXJjZXMiOlsic3JjL3NvdXJjZS1tYXAvQ2FyZC5qcyJdLCJ2ZXJzaW9uIjozfQ== */`;
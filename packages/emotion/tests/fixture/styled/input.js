@@ -0,0 +1,17 @@
+import styled from '@emotion/styled';
+import Link from './Link';
+
+const Button = styled.button`
+  color: ${(props) => props.color};
+  padding: 4px;
+`;
+
+const Anchor = styled(Link)({ textDecoration: 'none' });
+
+const Plain = styled(Link, { shouldForwardProp: () => true })`
+  color: red;
+`;
+
+const Targeted = styled('a', { target: 'link' })`
+  color: blue;
+`;
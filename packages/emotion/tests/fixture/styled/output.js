@@ -0,0 +1,22 @@
+import styled from '@emotion/styled';
+import Link from './Link';
+const Button = styled("button", {
+    target: "e14ccich0",
+    label: "Button"
+})`color:${(props)=>props.color};padding:4px;`;
+const Anchor = styled(Link, {
+    target: "e14ccich1",
+    label: "Anchor"
+})({
+    textDecoration: 'none'
+});
+const Plain = styled(Link, {
+    shouldForwardProp: ()=>true,
+    target: "e14ccich2",
+    label: "Plain"
+})`color:red;`;
+const Targeted = styled('a', {
+    target: 'link'
+})`
+  color: blue;
+`;
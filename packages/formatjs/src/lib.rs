@@ -0,0 +1,785 @@
+use base64::Engine;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256, Sha512};
+use std::fmt::Write;
+use swc_common::DUMMY_SP;
+use swc_common::errors::HANDLER;
+use swc_core::ecma::{
+    ast::*,
+    atoms::Atom,
+    utils::quote_ident,
+    visit::{as_folder, noop_visit_mut_type, Fold, FoldWith, VisitMut, VisitMutWith},
+};
+use swc_plugin_proxy::TransformPluginProgramMetadata;
+use swc_plugin_macro::plugin_transform;
+
+/// Functions whose first argument is a message descriptor.
+const FUNCTION_NAMES: [&str; 2] = ["defineMessage", "formatMessage"];
+
+/// Components whose attributes are a message descriptor.
+const COMPONENT_NAMES: [&str; 1] = ["FormattedMessage"];
+
+/// Options of formatjs, like those of `babel-plugin-formatjs`.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Pattern of the ids generated for the messages without one, from the
+    /// hash of their default message and description. Defaults to
+    /// `[sha512:contenthash:base64:6]`.
+    #[serde(default)]
+    pub id_interpolation_pattern: Option<String>,
+
+    /// Replace the default messages with their parsed ICU AST, so they needn't
+    /// be parsed at runtime.
+    #[serde(default)]
+    pub ast: bool,
+
+    /// Remove the default messages, for apps loading all of their messages.
+    #[serde(default)]
+    pub remove_default_message: bool,
+
+    /// Keep the whitespace of the default messages, which is otherwise
+    /// trimmed and collapsed.
+    #[serde(default)]
+    pub preserve_whitespace: bool,
+
+    /// Other functions whose first argument is a message descriptor, like
+    /// `$t`.
+    #[serde(default)]
+    pub additional_function_names: Vec<String>,
+
+    /// Other components whose attributes are a message descriptor.
+    #[serde(default)]
+    pub additional_component_names: Vec<String>,
+}
+
+/// Compiles the message descriptors of `defineMessages`, `defineMessage`,
+/// `formatMessage` and `<FormattedMessage>`: generates the missing ids,
+/// removes the descriptions, and normalizes or parses the default messages.
+pub fn formatjs(config: Config) -> impl Fold {
+    as_folder(FormatJs { config })
+}
+
+struct FormatJs {
+    config: Config,
+}
+
+/// The static parts of a message descriptor.
+#[derive(Default)]
+struct Descriptor {
+    id: Option<String>,
+    default_message: Option<String>,
+    /// The description, stringified if it is an object.
+    description: Option<String>,
+}
+
+impl FormatJs {
+    fn is_function(&self, name: &str) -> bool {
+        FUNCTION_NAMES.contains(&name) || self.config.additional_function_names.iter().any(|n| n == name)
+    }
+
+    fn is_component(&self, name: &str) -> bool {
+        COMPONENT_NAMES.contains(&name) || self.config.additional_component_names.iter().any(|n| n == name)
+    }
+
+    /// Completes `descriptor`, returning the id to add if it has none, and
+    /// the value replacing the default message, if any.
+    fn compile(&self, descriptor: &mut Descriptor) -> (Option<String>, Option<Expr>) {
+        let Some(message) = &mut descriptor.default_message else {
+            return (None, None);
+        };
+        if !self.config.preserve_whitespace {
+            *message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+        }
+
+        let id = match &descriptor.id {
+            Some(..) => None,
+            None => {
+                let content = match &descriptor.description {
+                    Some(description) => format!("{}#{}", message, description),
+                    None => message.clone(),
+                };
+                let pattern = self
+                    .config
+                    .id_interpolation_pattern
+                    .as_deref()
+                    .unwrap_or("[sha512:contenthash:base64:6]");
+                Some(interpolate_id(pattern, &content))
+            }
+        };
+
+        let value = if self.config.ast {
+            match parse_message(message) {
+                Ok(ast) => json_to_expr(&ast),
+                Err(err) => {
+                    tracing::warn!("formatjs: keeping `{}` as a string: {}", message, err);
+                    Expr::Lit(Lit::Str(message.as_str().into()))
+                }
+            }
+        } else {
+            Expr::Lit(Lit::Str(message.as_str().into()))
+        };
+        (id, Some(value))
+    }
+
+    /// Compiles the message descriptor `obj`.
+    fn compile_object(&self, obj: &mut ObjectLit) {
+        let mut descriptor = Descriptor::default();
+        for p in &obj.props {
+            let Some((key, value)) = key_value(p) else {
+                continue;
+            };
+            match key {
+                "id" => descriptor.id = string_value(value),
+                "defaultMessage" => descriptor.default_message = string_value(value),
+                "description" => descriptor.description = description_value(value),
+                _ => {}
+            }
+        }
+        if descriptor.default_message.is_none() {
+            return;
+        }
+
+        let (id, message) = self.compile(&mut descriptor);
+        obj.props.retain_mut(|p| {
+            let Some((key, _)) = key_value(p) else {
+                return true;
+            };
+            match key {
+                "description" => false,
+                "defaultMessage" if self.config.remove_default_message => false,
+                "defaultMessage" => {
+                    if let (PropOrSpread::Prop(p), Some(message)) = (p, &message) {
+                        if let Prop::KeyValue(KeyValueProp { value, .. }) = &mut **p {
+                            *value = Box::new(message.clone());
+                        }
+                    }
+                    true
+                }
+                _ => true,
+            }
+        });
+        if let Some(id) = id {
+            obj.props.insert(
+                0,
+                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(quote_ident!("id")),
+                    value: Box::new(Expr::Lit(Lit::Str(id.into()))),
+                }))),
+            );
+        }
+    }
+}
+
+impl VisitMut for FormatJs {
+    noop_visit_mut_type!();
+
+    fn visit_mut_call_expr(&mut self, call: &mut CallExpr) {
+        call.visit_mut_children_with(self);
+
+        let Callee::Expr(callee) = &call.callee else {
+            return;
+        };
+        let name = match &**callee {
+            Expr::Ident(i) => &i.sym,
+            Expr::Member(MemberExpr {
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => &prop.sym,
+            _ => return,
+        };
+        let Some(ExprOrSpread { spread: None, expr }) = call.args.first_mut() else {
+            return;
+        };
+        let Expr::Object(obj) = &mut **expr else {
+            return;
+        };
+
+        if name == "defineMessages" {
+            for p in &mut obj.props {
+                if let PropOrSpread::Prop(p) = p {
+                    if let Prop::KeyValue(KeyValueProp { value, .. }) = &mut **p {
+                        if let Expr::Object(descriptor) = &mut **value {
+                            self.compile_object(descriptor);
+                        }
+                    }
+                }
+            }
+        } else if self.is_function(name) {
+            self.compile_object(obj);
+        }
+    }
+
+    fn visit_mut_jsx_opening_element(&mut self, e: &mut JSXOpeningElement) {
+        e.visit_mut_children_with(self);
+
+        let JSXElementName::Ident(name) = &e.name else {
+            return;
+        };
+        if !self.is_component(&name.sym) {
+            return;
+        }
+
+        let mut descriptor = Descriptor::default();
+        for attr in &e.attrs {
+            let Some((key, value)) = jsx_attr(attr) else {
+                continue;
+            };
+            match key {
+                "id" => descriptor.id = value.and_then(jsx_string_value),
+                "defaultMessage" => descriptor.default_message = value.and_then(jsx_string_value),
+                "description" => {
+                    descriptor.description = value.and_then(|v| match v {
+                        JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                            expr: JSXExpr::Expr(e), ..
+                        }) => description_value(e),
+                        v => jsx_string_value(v),
+                    })
+                }
+                _ => {}
+            }
+        }
+        if descriptor.default_message.is_none() {
+            return;
+        }
+
+        let (id, message) = self.compile(&mut descriptor);
+        e.attrs.retain_mut(|attr| {
+            let Some((key, _)) = jsx_attr(attr) else {
+                return true;
+            };
+            match key {
+                "description" => false,
+                "defaultMessage" if self.config.remove_default_message => false,
+                "defaultMessage" => {
+                    if let (JSXAttrOrSpread::JSXAttr(attr), Some(message)) = (attr, &message) {
+                        attr.value = Some(match message {
+                            Expr::Lit(Lit::Str(s)) => JSXAttrValue::Lit(Lit::Str(s.clone())),
+                            message => JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                                span: DUMMY_SP,
+                                expr: JSXExpr::Expr(Box::new(message.clone())),
+                            }),
+                        });
+                    }
+                    true
+                }
+                _ => true,
+            }
+        });
+        if let Some(id) = id {
+            e.attrs.insert(
+                0,
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    span: DUMMY_SP,
+                    name: JSXAttrName::Ident(quote_ident!("id")),
+                    value: Some(JSXAttrValue::Lit(Lit::Str(id.into()))),
+                }),
+            );
+        }
+    }
+}
+
+/// Returns the key and the value of the property `p`.
+fn key_value(p: &PropOrSpread) -> Option<(&str, &Expr)> {
+    let PropOrSpread::Prop(p) = p else {
+        return None;
+    };
+    let Prop::KeyValue(KeyValueProp { key, value }) = &**p else {
+        return None;
+    };
+    let key = match key {
+        PropName::Ident(i) => &*i.sym,
+        PropName::Str(s) => &*s.value,
+        _ => return None,
+    };
+    Some((key, value))
+}
+
+/// Returns the name and the value of the JSX attribute `attr`.
+fn jsx_attr(attr: &JSXAttrOrSpread) -> Option<(&str, Option<&JSXAttrValue>)> {
+    let JSXAttrOrSpread::JSXAttr(JSXAttr {
+        name: JSXAttrName::Ident(name),
+        value,
+        ..
+    }) = attr
+    else {
+        return None;
+    };
+    Some((&name.sym, value.as_ref()))
+}
+
+/// Returns the value of the string literal, template literal without
+/// expressions, or concatenation of those `e`.
+fn string_value(e: &Expr) -> Option<String> {
+    match e {
+        Expr::Lit(Lit::Str(s)) => Some(s.value.to_string()),
+        Expr::Tpl(Tpl { exprs, quasis, .. }) if exprs.is_empty() => {
+            quasis.first().and_then(|q| q.cooked.as_ref()).map(|c| c.to_string())
+        }
+        Expr::Bin(BinExpr {
+            op: op!(bin, "+"),
+            left,
+            right,
+            ..
+        }) => Some(string_value(left)? + string_value(right)?.as_str()),
+        Expr::Paren(ParenExpr { expr, .. }) => string_value(expr),
+        _ => None,
+    }
+}
+
+fn jsx_string_value(value: &JSXAttrValue) -> Option<String> {
+    match value {
+        JSXAttrValue::Lit(Lit::Str(s)) => Some(s.value.to_string()),
+        JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::Expr(e), ..
+        }) => string_value(e),
+        _ => None,
+    }
+}
+
+/// Returns the description `e`, objects being stringified as JSON.
+fn description_value(e: &Expr) -> Option<String> {
+    match e {
+        Expr::Object(..) => stringify(e),
+        e => string_value(e),
+    }
+}
+
+/// Stringifies the literal `e` like `JSON.stringify`, keeping the order of
+/// the properties and writing integral numbers without a fraction, so that
+/// the ids hashing it match those of `babel-plugin-formatjs`.
+fn stringify(e: &Expr) -> Option<String> {
+    match e {
+        Expr::Lit(Lit::Null(..)) => Some("null".into()),
+        Expr::Lit(Lit::Bool(b)) => Some(b.value.to_string()),
+        Expr::Lit(Lit::Num(n)) if !n.value.is_finite() => Some("null".into()),
+        Expr::Lit(Lit::Num(n)) if n.value.fract() == 0.0 && n.value.abs() < 1e21 => {
+            Some(format!("{:.0}", n.value))
+        }
+        Expr::Lit(Lit::Num(n)) => Some(n.value.to_string()),
+        Expr::Object(ObjectLit { props, .. }) => {
+            let mut members = vec![];
+            for p in props {
+                let (key, value) = key_value(p)?;
+                members.push(format!("{}:{}", Value::from(key), stringify(value)?));
+            }
+            Some(format!("{{{}}}", members.join(",")))
+        }
+        e => string_value(e).map(|s| Value::String(s).to_string()),
+    }
+}
+
+/// Interpolates the `[hash:contenthash:digest:length]` placeholders of
+/// `pattern` with the hash of `content`, like `[sha512:contenthash:base64:6]`.
+fn interpolate_id(pattern: &str, content: &str) -> String {
+    let mut id = String::new();
+    let mut rest = pattern;
+    while let Some(start) = rest.find('[') {
+        let Some(end) = rest[start..].find(']') else {
+            break;
+        };
+        id.push_str(&rest[..start]);
+        let placeholder = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let parts: Vec<_> = placeholder.split(':').collect();
+        let Some(i) = parts.iter().position(|p| *p == "contenthash") else {
+            id.push_str(&format!("[{}]", placeholder));
+            continue;
+        };
+        let hash = match i.checked_sub(1).map(|i| parts[i]) {
+            Some("sha256") => Sha256::digest(content).to_vec(),
+            _ => Sha512::digest(content).to_vec(),
+        };
+        let digest = match parts.get(i + 1) {
+            Some(&"base64") => base64::engine::general_purpose::STANDARD.encode(hash),
+            _ => hash.iter().fold(String::new(), |mut hex, b| {
+                let _ = write!(hex, "{:02x}", b);
+                hex
+            }),
+        };
+        let length = parts.get(i + 2).and_then(|l| l.parse().ok()).unwrap_or(digest.len());
+        id.push_str(&digest[..length.min(digest.len())]);
+    }
+    id.push_str(rest);
+    id
+}
+
+/// Types of the elements of the AST of ICU messages, as numbered by
+/// `@formatjs/icu-messageformat-parser`.
+const LITERAL: u8 = 0;
+const ARGUMENT: u8 = 1;
+const NUMBER: u8 = 2;
+const DATE: u8 = 3;
+const TIME: u8 = 4;
+const SELECT: u8 = 5;
+const PLURAL: u8 = 6;
+const POUND: u8 = 7;
+const TAG: u8 = 8;
+
+/// Parses the ICU message `message` into the AST of
+/// `@formatjs/icu-messageformat-parser`, without locations.
+fn parse_message(message: &str) -> Result<Value, String> {
+    let mut parser = Parser {
+        chars: message.chars().collect(),
+        pos: 0,
+    };
+    let elements = parser.parse_message(0, None, None)?;
+    if parser.pos < parser.chars.len() {
+        return Err(format!("unexpected `{}` at {}", parser.chars[parser.pos], parser.pos));
+    }
+    Ok(Value::Array(elements))
+}
+
+/// Kinds of the arguments whose options are being parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParentArg {
+    Plural,
+    Select,
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if self.peek() != Some(c) {
+            return Err(format!("expected `{}` at {}", c, self.pos));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Reads the characters while `f` holds.
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(&f) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// Parses elements up to the end of the message, the `}` closing an
+    /// option at `depth` above 0, or the closing tag of `tag`.
+    fn parse_message(&mut self, depth: usize, parent: Option<ParentArg>, tag: Option<&str>) -> Result<Vec<Value>, String> {
+        let mut elements: Vec<Value> = vec![];
+        loop {
+            let element = match self.peek() {
+                None => break,
+                Some('}') if depth > 0 => break,
+                Some('<') if self.peek_at(1) == Some('/') => {
+                    if tag.is_none() {
+                        return Err(format!("unmatched closing tag at {}", self.pos));
+                    }
+                    break;
+                }
+                Some('{') => self.parse_argument(depth)?,
+                Some('#') if parent == Some(ParentArg::Plural) => {
+                    self.pos += 1;
+                    json!({ "type": POUND })
+                }
+                Some('<') if self.peek_at(1).is_some_and(is_tag_char) => self.parse_tag(depth, parent)?,
+                Some(..) => json!({ "type": LITERAL, "value": self.parse_literal(depth, parent) }),
+            };
+
+            // Literals split by quotes or self-closing tags are merged.
+            match (elements.last_mut(), &element) {
+                (Some(Value::Object(last)), Value::Object(next))
+                    if last["type"] == LITERAL && next["type"] == LITERAL =>
+                {
+                    let text = last["value"].as_str().unwrap_or_default().to_string()
+                        + next["value"].as_str().unwrap_or_default();
+                    last.insert("value".into(), Value::String(text));
+                }
+                _ => elements.push(element),
+            }
+        }
+
+        if let Some(tag) = tag {
+            if self.peek() != Some('<') {
+                return Err(format!("unclosed tag `{}`", tag));
+            }
+        }
+        Ok(elements)
+    }
+
+    /// Parses literal text, unescaping quotes.
+    fn parse_literal(&mut self, depth: usize, parent: Option<ParentArg>) -> String {
+        let mut text = String::new();
+        while let Some(c) = self.peek() {
+            match c {
+                '{' => break,
+                '}' if depth > 0 => break,
+                '#' if parent == Some(ParentArg::Plural) => break,
+                '<' if self.peek_at(1).is_some_and(|c| c == '/' || is_tag_char(c)) => {
+                    // Kept as text if self-closing, like `<br/>`.
+                    if !text.is_empty() || !self.is_self_closing_tag() {
+                        break;
+                    }
+                    let end = self.pos + self.chars[self.pos..].iter().position(|c| *c == '>').unwrap_or(0);
+                    text.extend(&self.chars[self.pos..=end]);
+                    self.pos = end + 1;
+                }
+                '\'' => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some('\'') => {
+                            text.push('\'');
+                            self.pos += 1;
+                        }
+                        Some('{' | '}' | '<' | '>') => self.parse_quoted(&mut text),
+                        Some('#') if parent == Some(ParentArg::Plural) => self.parse_quoted(&mut text),
+                        _ => text.push('\''),
+                    }
+                }
+                c => {
+                    text.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+        text
+    }
+
+    /// Parses the text quoted up to the next single quote.
+    fn parse_quoted(&mut self, text: &mut String) {
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            if c != '\'' {
+                text.push(c);
+            } else if self.peek() == Some('\'') {
+                text.push('\'');
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn is_self_closing_tag(&self) -> bool {
+        let mut i = self.pos + 1;
+        while self.chars.get(i).is_some_and(|c| is_tag_char(*c)) {
+            i += 1;
+        }
+        while self.chars.get(i).is_some_and(|c| c.is_whitespace()) {
+            i += 1;
+        }
+        self.chars.get(i) == Some(&'/') && self.chars.get(i + 1) == Some(&'>')
+    }
+
+    /// Parses `<b>children</b>`.
+    fn parse_tag(&mut self, depth: usize, parent: Option<ParentArg>) -> Result<Value, String> {
+        if self.is_self_closing_tag() {
+            return Ok(json!({ "type": LITERAL, "value": self.parse_literal(depth, parent) }));
+        }
+
+        self.expect('<')?;
+        let name = self.take_while(is_tag_char);
+        self.expect('>')?;
+        let children = self.parse_message(depth, parent, Some(&name))?;
+
+        self.expect('<')?;
+        self.expect('/')?;
+        let closing = self.take_while(is_tag_char);
+        if closing != name {
+            return Err(format!("mismatched closing tag `{}` of `{}`", closing, name));
+        }
+        self.expect('>')?;
+        Ok(json!({ "type": TAG, "value": name, "children": children }))
+    }
+
+    /// Parses `{name}`, `{name, number, style}`, `{name, plural, ...}` and
+    /// the like.
+    fn parse_argument(&mut self, depth: usize) -> Result<Value, String> {
+        self.expect('{')?;
+        self.skip_whitespace();
+        let name = self.take_while(|c| !c.is_whitespace() && c != ',' && c != '}');
+        if name.is_empty() {
+            return Err(format!("empty argument at {}", self.pos));
+        }
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(json!({ "type": ARGUMENT, "value": name }));
+        }
+
+        self.expect(',')?;
+        self.skip_whitespace();
+        let kind = self.take_while(|c| c.is_ascii_alphabetic());
+        self.skip_whitespace();
+
+        let value = match &*kind {
+            "number" | "date" | "time" => {
+                let ty = match &*kind {
+                    "number" => NUMBER,
+                    "date" => DATE,
+                    _ => TIME,
+                };
+                let mut style = Value::Null;
+                if self.peek() == Some(',') {
+                    self.pos += 1;
+                    let text = self.take_while(|c| c != '}');
+                    let text = text.trim();
+                    // Skeletons are formatted from options parsed like
+                    // `@formatjs/icu-skeleton-parser` does, which isn't
+                    // implemented, so such messages are left to the runtime.
+                    if text.starts_with("::") {
+                        return Err(format!("skeleton `{}` of `{}` can't be pre-parsed", text, name));
+                    }
+                    style = Value::String(text.to_string());
+                }
+                json!({ "type": ty, "value": name, "style": style })
+            }
+            "select" | "plural" | "selectordinal" => {
+                self.expect(',')?;
+                self.skip_whitespace();
+
+                let mut offset = 0;
+                if kind != "select" && self.chars[self.pos..].starts_with(&['o', 'f', 'f', 's', 'e', 't', ':']) {
+                    self.pos += "offset:".len();
+                    self.skip_whitespace();
+                    let n = self.take_while(|c| c.is_ascii_digit() || c == '-');
+                    offset = n.parse::<i64>().map_err(|_| format!("invalid offset at {}", self.pos))?;
+                }
+
+                let parent = if kind == "select" {
+                    ParentArg::Select
+                } else {
+                    ParentArg::Plural
+                };
+                let mut options = Map::new();
+                loop {
+                    self.skip_whitespace();
+                    if matches!(self.peek(), Some('}') | None) {
+                        break;
+                    }
+                    let key = self.take_while(|c| !c.is_whitespace() && c != '{' && c != '}');
+                    if key.is_empty() {
+                        return Err(format!("expected an option at {}", self.pos));
+                    }
+                    self.skip_whitespace();
+                    self.expect('{')?;
+                    let message = self.parse_message(depth + 1, Some(parent), None)?;
+                    self.expect('}')?;
+                    options.insert(key, json!({ "value": message }));
+                }
+                if !options.contains_key("other") {
+                    return Err(format!("missing `other` option of `{}`", name));
+                }
+
+                if kind == "select" {
+                    json!({ "type": SELECT, "value": name, "options": options })
+                } else {
+                    let plural_type = if kind == "plural" { "cardinal" } else { "ordinal" };
+                    json!({
+                        "type": PLURAL,
+                        "value": name,
+                        "options": options,
+                        "offset": offset,
+                        "pluralType": plural_type,
+                    })
+                }
+            }
+            _ => return Err(format!("invalid type `{}` of `{}`", kind, name)),
+        };
+
+        self.skip_whitespace();
+        self.expect('}')?;
+        Ok(value)
+    }
+}
+
+fn is_tag_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | ':')
+}
+
+/// Creates the literal of `value`.
+fn json_to_expr(value: &Value) -> Expr {
+    match value {
+        Value::Null => Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+        Value::Bool(b) => Expr::Lit(Lit::Bool((*b).into())),
+        Value::Number(n) => {
+            let n = n.as_f64().unwrap_or(f64::NAN);
+            if n < 0.0 {
+                Expr::Unary(UnaryExpr {
+                    span: DUMMY_SP,
+                    op: op!(unary, "-"),
+                    arg: (-n).into(),
+                })
+            } else {
+                Expr::Lit(Lit::Num(n.into()))
+            }
+        }
+        Value::String(s) => Expr::Lit(Lit::Str(s.as_str().into())),
+        Value::Array(values) => Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: values
+                .iter()
+                .map(|v| Some(Box::new(json_to_expr(v)).into()))
+                .collect(),
+        }),
+        Value::Object(props) => Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props: props
+                .iter()
+                .map(|(key, v)| {
+                    // Option keys like `=0` need quotes.
+                    let key = if Ident::verify_symbol(key).is_ok() {
+                        PropName::Ident(quote_ident!(Atom::from(key.as_str())))
+                    } else {
+                        PropName::Str(key.as_str().into())
+                    };
+                    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key,
+                        value: Box::new(json_to_expr(v)),
+                    })))
+                })
+                .collect(),
+        }),
+    }
+}
+
+/// Reports `msg` through the diagnostics handler of swc, or logs it if there
+/// is none.
+fn error(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.err(msg));
+    } else {
+        tracing::error!("{}", msg);
+    }
+}
+
+#[plugin_transform]
+pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    let Some(json) = _metadata.get_transform_plugin_config() else {
+        error("formatjs: missing config");
+        return program;
+    };
+    // Reported rather than panicking, which would abort the whole compilation.
+    let config = match serde_json::from_str::<Config>(&json) {
+        Ok(config) => config,
+        Err(err) => {
+            error(&format!("formatjs: invalid config `{}`: {}", json, err));
+            return program;
+        }
+    };
+
+    program.fold_with(&mut formatjs(config))
+}
@@ -0,0 +1,18 @@
+import { defineMessages } from 'react-intl';
+
+export default defineMessages({
+  plain: { id: 'plain', defaultMessage: 'Hello' },
+  argument: { id: 'argument', defaultMessage: 'Hello, {name}!' },
+  number: { id: 'number', defaultMessage: 'Price: {price, number} or {ratio, number, percent}' },
+  skeleton: { id: 'skeleton', defaultMessage: 'Price: {price, number, ::currency/EUR}' },
+  date: { id: 'date', defaultMessage: 'On {day, date, short} at {time, time}' },
+  plural: {
+    id: 'plural',
+    defaultMessage: '{count, plural, offset:1 =0 {no items} one {# item} other {# items}}',
+  },
+  select: { id: 'select', defaultMessage: '{gender, select, male {He} female {She} other {They}} said #' },
+  ordinal: { id: 'ordinal', defaultMessage: '{n, selectordinal, one {#st} two {#nd} other {#th}}' },
+  tag: { id: 'tag', defaultMessage: 'Read the <link>terms</link>.<br/>' },
+  quotes: { id: 'quotes', defaultMessage: "It''s '{literal}' text" },
+  invalid: { id: 'invalid', defaultMessage: '{count, plural, one {item}}' },
+});
@@ -0,0 +1,243 @@
+import { defineMessages } from 'react-intl';
+export default defineMessages({
+    plain: {
+        id: 'plain',
+        defaultMessage: [
+            {
+                type: 0,
+                value: "Hello"
+            }
+        ]
+    },
+    argument: {
+        id: 'argument',
+        defaultMessage: [
+            {
+                type: 0,
+                value: "Hello, "
+            },
+            {
+                type: 1,
+                value: "name"
+            },
+            {
+                type: 0,
+                value: "!"
+            }
+        ]
+    },
+    number: {
+        id: 'number',
+        defaultMessage: [
+            {
+                type: 0,
+                value: "Price: "
+            },
+            {
+                style: null,
+                type: 2,
+                value: "price"
+            },
+            {
+                type: 0,
+                value: " or "
+            },
+            {
+                style: "percent",
+                type: 2,
+                value: "ratio"
+            }
+        ]
+    },
+    skeleton: {
+        id: 'skeleton',
+        defaultMessage: "Price: {price, number, ::currency/EUR}"
+    },
+    date: {
+        id: 'date',
+        defaultMessage: [
+            {
+                type: 0,
+                value: "On "
+            },
+            {
+                style: "short",
+                type: 3,
+                value: "day"
+            },
+            {
+                type: 0,
+                value: " at "
+            },
+            {
+                style: null,
+                type: 4,
+                value: "time"
+            }
+        ]
+    },
+    plural: {
+        id: 'plural',
+        defaultMessage: [
+            {
+                offset: 1,
+                options: {
+                    "=0": {
+                        value: [
+                            {
+                                type: 0,
+                                value: "no items"
+                            }
+                        ]
+                    },
+                    one: {
+                        value: [
+                            {
+                                type: 7
+                            },
+                            {
+                                type: 0,
+                                value: " item"
+                            }
+                        ]
+                    },
+                    other: {
+                        value: [
+                            {
+                                type: 7
+                            },
+                            {
+                                type: 0,
+                                value: " items"
+                            }
+                        ]
+                    }
+                },
+                pluralType: "cardinal",
+                type: 6,
+                value: "count"
+            }
+        ]
+    },
+    select: {
+        id: 'select',
+        defaultMessage: [
+            {
+                options: {
+                    female: {
+                        value: [
+                            {
+                                type: 0,
+                                value: "She"
+                            }
+                        ]
+                    },
+                    male: {
+                        value: [
+                            {
+                                type: 0,
+                                value: "He"
+                            }
+                        ]
+                    },
+                    other: {
+                        value: [
+                            {
+                                type: 0,
+                                value: "They"
+                            }
+                        ]
+                    }
+                },
+                type: 5,
+                value: "gender"
+            },
+            {
+                type: 0,
+                value: " said #"
+            }
+        ]
+    },
+    ordinal: {
+        id: 'ordinal',
+        defaultMessage: [
+            {
+                offset: 0,
+                options: {
+                    one: {
+                        value: [
+                            {
+                                type: 7
+                            },
+                            {
+                                type: 0,
+                                value: "st"
+                            }
+                        ]
+                    },
+                    other: {
+                        value: [
+                            {
+                                type: 7
+                            },
+                            {
+                                type: 0,
+                                value: "th"
+                            }
+                        ]
+                    },
+                    two: {
+                        value: [
+                            {
+                                type: 7
+                            },
+                            {
+                                type: 0,
+                                value: "nd"
+                            }
+                        ]
+                    }
+                },
+                pluralType: "ordinal",
+                type: 6,
+                value: "n"
+            }
+        ]
+    },
+    tag: {
+        id: 'tag',
+        defaultMessage: [
+            {
+                type: 0,
+                value: "Read the "
+            },
+            {
+                children: [
+                    {
+                        type: 0,
+                        value: "terms"
+                    }
+                ],
+                type: 8,
+                value: "link"
+            },
+            {
+                type: 0,
+                value: ".<br/>"
+            }
+        ]
+    },
+    quotes: {
+        id: 'quotes',
+        defaultMessage: [
+            {
+                type: 0,
+                value: "It's {literal} text"
+            }
+        ]
+    },
+    invalid: {
+        id: 'invalid',
+        defaultMessage: "{count, plural, one {item}}"
+    }
+});
@@ -0,0 +1,22 @@
+import { defineMessages, defineMessage } from 'react-intl';
+
+export const messages = defineMessages({
+  greeting: {
+    defaultMessage: 'Hello,   {name}!',
+    description: 'Greeting on the home page',
+  },
+  farewell: {
+    id: 'app.farewell',
+    defaultMessage: 'Goodbye' + ' for now',
+  },
+  described: {
+    defaultMessage: `Welcome`,
+    description: { context: 'header', maxLength: 20 },
+  },
+});
+
+export const title = defineMessage({ defaultMessage: 'Title' });
+
+export function render(intl) {
+  return intl.formatMessage({ defaultMessage: 'Hi' }, { name: 'x' });
+}
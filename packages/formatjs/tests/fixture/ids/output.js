@@ -0,0 +1,27 @@
+import { defineMessages, defineMessage } from 'react-intl';
+export const messages = defineMessages({
+    greeting: {
+        id: "O3sAoy",
+        defaultMessage: "Hello, {name}!"
+    },
+    farewell: {
+        id: 'app.farewell',
+        defaultMessage: "Goodbye for now"
+    },
+    described: {
+        id: "G70jYl",
+        defaultMessage: "Welcome"
+    }
+});
+export const title = defineMessage({
+    id: "9a9+ww",
+    defaultMessage: "Title"
+});
+export function render(intl) {
+    return intl.formatMessage({
+        id: "RcpVzK",
+        defaultMessage: "Hi"
+    }, {
+        name: 'x'
+    });
+}
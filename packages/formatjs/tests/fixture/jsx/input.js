@@ -0,0 +1,13 @@
+import { FormattedMessage } from 'react-intl';
+
+export function Inbox({ count }) {
+  return (
+    <FormattedMessage
+      description="Number of unread messages"
+      defaultMessage="You have {count, plural, one {# message} other {# messages}}"
+      values={{ count }}
+    />
+  );
+}
+
+export const Label = () => <FormattedMessage id="label" defaultMessage="Label" />;
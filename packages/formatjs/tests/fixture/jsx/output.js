@@ -0,0 +1,47 @@
+import { FormattedMessage } from 'react-intl';
+export function Inbox({ count }) {
+    return <FormattedMessage id="app.ed5aee53" defaultMessage={[
+        {
+            type: 0,
+            value: "You have "
+        },
+        {
+            offset: 0,
+            options: {
+                one: {
+                    value: [
+                        {
+                            type: 7
+                        },
+                        {
+                            type: 0,
+                            value: " message"
+                        }
+                    ]
+                },
+                other: {
+                    value: [
+                        {
+                            type: 7
+                        },
+                        {
+                            type: 0,
+                            value: " messages"
+                        }
+                    ]
+                }
+            },
+            pluralType: "cardinal",
+            type: 6,
+            value: "count"
+        }
+    ]} values={{
+        count
+    }}/>;
+}
+export const Label = ()=><FormattedMessage id="label" defaultMessage={[
+        {
+            type: 0,
+            value: "Label"
+        }
+    ]}/>;
@@ -0,0 +1,3 @@
+export const label = $t({ id: 'label', defaultMessage: 'Label', description: 'A label' });
+
+export const title = formatMessage({ defaultMessage: 'Title' });
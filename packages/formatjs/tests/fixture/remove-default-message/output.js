@@ -0,0 +1,6 @@
+export const label = $t({
+    id: 'label'
+});
+export const title = formatMessage({
+    id: "9a9+ww"
+});
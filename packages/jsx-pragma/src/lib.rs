@@ -0,0 +1,249 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use swc_common::{comments::Comments, Spanned, DUMMY_SP};
+use swc_common::errors::HANDLER;
+use swc_core::ecma::{
+    ast::*,
+    atoms::Atom,
+    utils::{collect_decls, quote_ident},
+    visit::{as_folder, noop_visit_mut_type, noop_visit_type, Fold, FoldWith, Visit, VisitMut, VisitWith},
+};
+use swc_plugin_proxy::TransformPluginProgramMetadata;
+use swc_plugin_macro::plugin_transform;
+
+/// Options of jsx-pragma.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Function creating elements. Defaults to `React.createElement`.
+    #[serde(default)]
+    pub pragma: Option<String>,
+
+    /// Component of fragments. Defaults to `React.Fragment`.
+    #[serde(default)]
+    pub pragma_frag: Option<String>,
+
+    /// Module exporting them. Defaults to `react`.
+    #[serde(default)]
+    pub import_source: Option<String>,
+}
+
+/// Imports the pragma of the classic JSX runtime in the modules using JSX
+/// without declaring it, like `import React from 'react'` for
+/// `React.createElement` or `import { h } from 'preact'` for `h`.
+pub fn jsx_pragma<C: Comments>(config: Config, comments: Option<C>) -> impl Fold {
+    as_folder(JsxPragma { config, comments })
+}
+
+struct JsxPragma<C: Comments> {
+    config: Config,
+    comments: Option<C>,
+}
+
+/// The pragmas of a module, from the config or its comments.
+struct Pragmas {
+    pragma: String,
+    pragma_frag: String,
+    /// `None` if the pragmas come from comments without `@jsxImportSource`,
+    /// the module then being responsible for them.
+    import_source: Option<String>,
+}
+
+impl<C: Comments> JsxPragma<C> {
+    /// Returns the pragmas of `m`, or `None` if it uses the automatic runtime.
+    fn pragmas(&self, m: &Module) -> Option<Pragmas> {
+        let mut pragmas = Pragmas {
+            pragma: self.config.pragma.clone().unwrap_or_else(|| "React.createElement".into()),
+            pragma_frag: self.config.pragma_frag.clone().unwrap_or_else(|| "React.Fragment".into()),
+            import_source: Some(self.config.import_source.clone().unwrap_or_else(|| "react".into())),
+        };
+
+        let (Some(comments), Some(first)) = (&self.comments, m.body.first()) else {
+            return Some(pragmas);
+        };
+        let (mut overridden, mut import_source) = (false, None);
+        for c in comments.get_leading(first.span().lo).unwrap_or_default() {
+            let mut words = c.text.split_whitespace().map(|w| w.trim_start_matches('*'));
+            while let Some(word) = words.next() {
+                let value = || words.clone().next().map(str::to_string);
+                match word {
+                    "@jsxRuntime" if value().as_deref() == Some("automatic") => return None,
+                    "@jsx" => {
+                        if let Some(pragma) = value() {
+                            overridden |= root(&pragma) != root(&pragmas.pragma);
+                            pragmas.pragma = pragma;
+                        }
+                    }
+                    "@jsxFrag" => {
+                        if let Some(pragma_frag) = value() {
+                            overridden |= root(&pragma_frag) != root(&pragmas.pragma_frag);
+                            pragmas.pragma_frag = pragma_frag;
+                        }
+                    }
+                    "@jsxImportSource" => import_source = value(),
+                    _ => {}
+                }
+            }
+        }
+
+        if import_source.is_some() {
+            pragmas.import_source = import_source;
+        } else if overridden {
+            // Only the module knows where its own pragmas come from.
+            pragmas.import_source = None;
+        }
+        Some(pragmas)
+    }
+}
+
+impl<C: Comments> VisitMut for JsxPragma<C> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        let mut usage = JsxUsage::default();
+        m.visit_with(&mut usage);
+        if !usage.elements && !usage.fragments {
+            return;
+        }
+        let Some(Pragmas {
+            pragma,
+            pragma_frag,
+            import_source: Some(import_source),
+        }) = self.pragmas(m)
+        else {
+            return;
+        };
+
+        let declared = collect_decls::<Id, _>(&*m);
+        let is_declared = |name: &str| declared.iter().any(|id| &*id.0 == name);
+
+        // Both the factory and the fragment may come from the same root.
+        let mut missing = BTreeMap::new();
+        for (used, pragma) in [(usage.elements, &pragma), (usage.fragments, &pragma_frag)] {
+            let name = root(pragma);
+            if used && !is_declared(name) {
+                // `React.createElement` is a member of the default export,
+                // `h` is exported by name.
+                missing.insert(Atom::from(name), pragma.contains('.'));
+            }
+        }
+        if missing.is_empty() {
+            return;
+        }
+
+        let mut specifiers: Vec<_> = missing
+            .into_iter()
+            .map(|(name, default)| {
+                let local = quote_ident!(name).into();
+                if default {
+                    ImportSpecifier::Default(ImportDefaultSpecifier { span: DUMMY_SP, local })
+                } else {
+                    ImportSpecifier::Named(ImportNamedSpecifier {
+                        span: DUMMY_SP,
+                        local,
+                        imported: None,
+                        is_type_only: false,
+                    })
+                }
+            })
+            .collect();
+
+        // Added to an import of the same module if there is one, like
+        // `import React, { useState } from 'react'`.
+        let existing = m.body.iter_mut().find_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import))
+                if !import.type_only && *import.src.value == *import_source =>
+            {
+                Some(import)
+            }
+            _ => None,
+        });
+        if let Some(import) = existing {
+            if !import.specifiers.iter().any(|s| s.is_namespace()) {
+                let has_default = import.specifiers.iter().any(|s| s.is_default());
+                // A default import under another name needs an import of its
+                // own.
+                specifiers.retain(|s| match s {
+                    ImportSpecifier::Default(..) if has_default => true,
+                    ImportSpecifier::Default(..) => {
+                        import.specifiers.insert(0, s.clone());
+                        false
+                    }
+                    _ => {
+                        import.specifiers.push(s.clone());
+                        false
+                    }
+                });
+            }
+        }
+        if specifiers.is_empty() {
+            return;
+        }
+
+        let import = ImportDecl {
+            span: DUMMY_SP,
+            specifiers,
+            src: Box::new(Atom::from(import_source).into()),
+            type_only: false,
+            with: None,
+            phase: Default::default(),
+        };
+        m.body.insert(0, ModuleItem::ModuleDecl(ModuleDecl::Import(import)));
+    }
+}
+
+/// Records whether a module uses JSX elements and fragments.
+#[derive(Default)]
+struct JsxUsage {
+    elements: bool,
+    fragments: bool,
+}
+
+impl Visit for JsxUsage {
+    noop_visit_type!();
+
+    fn visit_jsx_element(&mut self, e: &JSXElement) {
+        self.elements = true;
+        e.visit_children_with(self);
+    }
+
+    fn visit_jsx_fragment(&mut self, f: &JSXFragment) {
+        // Fragments are created by the factory too.
+        self.elements = true;
+        self.fragments = true;
+        f.visit_children_with(self);
+    }
+}
+
+/// Returns the binding of `pragma`, like `React` of `React.createElement`.
+fn root(pragma: &str) -> &str {
+    pragma.split('.').next().unwrap_or(pragma)
+}
+
+/// Reports `msg` through the diagnostics handler of swc, or logs it if there
+/// is none.
+fn error(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.err(msg));
+    } else {
+        tracing::error!("{}", msg);
+    }
+}
+
+#[plugin_transform]
+pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    let Some(json) = _metadata.get_transform_plugin_config() else {
+        error("jsx-pragma: missing config");
+        return program;
+    };
+    // Reported rather than panicking, which would abort the whole compilation.
+    let config = match serde_json::from_str::<Config>(&json) {
+        Ok(config) => config,
+        Err(err) => {
+            error(&format!("jsx-pragma: invalid config `{}`: {}", json, err));
+            return program;
+        }
+    };
+
+    program.fold_with(&mut jsx_pragma(config, _metadata.comments))
+}
@@ -0,0 +1,2 @@
+/** @jsxRuntime automatic */
+export const App = () => <p />;
@@ -0,0 +1 @@
+/** @jsxRuntime automatic */ export const App = ()=><p/>;
@@ -0,0 +1,2 @@
+// @jsx jsx
+export const App = () => <p css={{ color: 'red' }} />;
@@ -0,0 +1,4 @@
+// @jsx jsx
+export const App = ()=><p css={{
+        color: 'red'
+    }}/>;
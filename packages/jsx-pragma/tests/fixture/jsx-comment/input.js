@@ -0,0 +1,2 @@
+/** @jsx h @jsxFrag Fragment @jsxImportSource preact */
+export const App = () => <><p /></>;
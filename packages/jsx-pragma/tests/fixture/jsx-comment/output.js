@@ -0,0 +1,2 @@
+/** @jsx h @jsxFrag Fragment @jsxImportSource preact */ import { Fragment, h } from "preact";
+export const App = ()=><><p/></>;
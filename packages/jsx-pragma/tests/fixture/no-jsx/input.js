@@ -0,0 +1 @@
+export const sum = (a, b) => a + b;
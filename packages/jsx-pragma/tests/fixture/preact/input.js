@@ -0,0 +1,11 @@
+import { render } from 'preact';
+
+export function App() {
+  return (
+    <>
+      <span />
+    </>
+  );
+}
+
+render(<App />, document.body);
@@ -0,0 +1,7 @@
+import { render, Fragment, h } from 'preact';
+export function App() {
+    return <>
+      <span/>
+    </>;
+}
+render(<App/>, document.body);
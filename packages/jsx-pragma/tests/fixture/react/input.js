@@ -0,0 +1,5 @@
+import { useState } from 'react';
+
+export function App() {
+  return <div />;
+}
@@ -0,0 +1,4 @@
+import React, { useState } from 'react';
+export function App() {
+    return <div/>;
+}
@@ -0,0 +1,228 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::mem::take;
+use swc_common::{
+    collections::{AHashMap, AHashSet},
+    DUMMY_SP,
+};
+use swc_common::errors::HANDLER;
+use swc_core::ecma::{
+    ast::*,
+    atoms::Atom,
+    utils::collect_decls,
+    visit::{as_folder, noop_visit_mut_type, noop_visit_type, Fold, FoldWith, Visit, VisitMut, VisitMutWith, VisitWith},
+};
+use swc_plugin_proxy::TransformPluginProgramMetadata;
+use swc_plugin_macro::plugin_transform;
+
+/// Packages cherry-picked if none are configured.
+const DEFAULT_PACKAGES: [&str; 3] = ["lodash", "lodash/fp", "lodash-es"];
+
+/// Options of lodash.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Packages whose members are modules of their own, like `lodash/debounce`.
+    /// Defaults to `lodash`, `lodash/fp` and `lodash-es`.
+    #[serde(default)]
+    pub packages: Option<Vec<String>>,
+}
+
+/// Rewrites the imports of lodash into imports of the modules of the members
+/// used, like `babel-plugin-lodash`: `import { debounce } from 'lodash'` and
+/// `_.debounce` of `import _ from 'lodash'` both import `lodash/debounce`.
+pub fn lodash(config: Config) -> impl Fold {
+    let packages = match config.packages {
+        Some(packages) => packages,
+        None => DEFAULT_PACKAGES.iter().map(|p| p.to_string()).collect(),
+    };
+
+    as_folder(Lodash {
+        packages,
+        namespaces: Default::default(),
+        picked: Default::default(),
+        declared: Default::default(),
+    })
+}
+
+struct Lodash {
+    packages: Vec<String>,
+    /// Default and namespace imports of the packages, like `_`.
+    namespaces: AHashMap<Id, Atom>,
+    /// Locals of the members used through [Lodash::namespaces], by package and
+    /// member.
+    picked: BTreeMap<(Atom, Atom), Ident>,
+    /// Bindings of the module, which the picked locals must not shadow.
+    declared: AHashSet<Id>,
+}
+
+impl Lodash {
+    /// Returns the local of `member` of `package`, like `_debounce`.
+    fn pick(&mut self, package: &Atom, member: &Atom) -> Ident {
+        let key = (package.clone(), member.clone());
+        if let Some(local) = self.picked.get(&key) {
+            return local.clone();
+        }
+
+        let mut name = format!("_{}", member);
+        let mut n = 1;
+        while self.declared.iter().any(|id| *id.0 == name) || self.picked.values().any(|i| *i.sym == name) {
+            n += 1;
+            name = format!("_{}{}", member, n);
+        }
+
+        let local = Ident::new_no_ctxt(name.into(), DUMMY_SP);
+        self.picked.insert(key, local.clone());
+        local
+    }
+}
+
+impl VisitMut for Lodash {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        self.declared = collect_decls(&*m);
+
+        for item in &m.body {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+                continue;
+            };
+            if !self.packages.iter().any(|p| *p == *import.src.value) {
+                continue;
+            }
+
+            for s in &import.specifiers {
+                if let ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) = s
+                {
+                    self.namespaces.insert(local.to_id(), import.src.value.clone());
+                }
+            }
+        }
+
+        if !self.namespaces.is_empty() {
+            m.visit_mut_children_with(self);
+        }
+
+        // Namespaces used otherwise than through their members, like `_(users)`.
+        let mut used = UsedIdents::default();
+        m.visit_with(&mut used);
+
+        for item in take(&mut m.body) {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(mut import)) = item else {
+                m.body.push(item);
+                continue;
+            };
+            if import.type_only || !self.packages.iter().any(|p| *p == *import.src.value) {
+                m.body.push(ModuleItem::ModuleDecl(ModuleDecl::Import(import)));
+                continue;
+            }
+
+            let src = import.src.value.clone();
+            let mut picked = vec![];
+            import.specifiers.retain(|s| match s {
+                ImportSpecifier::Named(ImportNamedSpecifier {
+                    local,
+                    imported,
+                    is_type_only: false,
+                    ..
+                }) => {
+                    let member = imported.as_ref().map_or(&local.sym, |i| i.atom());
+                    picked.push(import_default(local.clone(), &src, member));
+                    false
+                }
+                ImportSpecifier::Named(..) => true,
+                ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => used.0.contains(&local.to_id()),
+            });
+            picked.extend(
+                self.picked
+                    .iter()
+                    .filter(|((package, _), _)| *package == src)
+                    .map(|((_, member), local)| import_default(local.clone(), &src, member)),
+            );
+
+            // Dropped once all of its specifiers are picked.
+            if !import.specifiers.is_empty() || picked.is_empty() {
+                m.body.push(ModuleItem::ModuleDecl(ModuleDecl::Import(import)));
+            }
+            m.body.extend(picked);
+        }
+
+        self.namespaces.clear();
+        self.picked.clear();
+    }
+
+    // `_.debounce`
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        if let Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) = e
+        {
+            if let Expr::Ident(obj) = &**obj {
+                if let Some(package) = self.namespaces.get(&obj.to_id()).cloned() {
+                    *e = Expr::Ident(self.pick(&package, &prop.sym));
+                    return;
+                }
+            }
+        }
+
+        e.visit_mut_children_with(self);
+    }
+}
+
+/// Creates `import local from 'package/member'`.
+fn import_default(local: Ident, package: &Atom, member: &Atom) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+        span: DUMMY_SP,
+        specifiers: vec![ImportSpecifier::Default(ImportDefaultSpecifier { span: DUMMY_SP, local })],
+        src: Box::new(Atom::from(format!("{}/{}", package, member)).into()),
+        type_only: false,
+        with: None,
+        phase: Default::default(),
+    }))
+}
+
+/// Collects the identifiers used outside of imports.
+#[derive(Default)]
+struct UsedIdents(AHashSet<Id>);
+
+impl Visit for UsedIdents {
+    noop_visit_type!();
+
+    fn visit_import_decl(&mut self, _: &ImportDecl) {}
+
+    fn visit_ident(&mut self, i: &Ident) {
+        self.0.insert(i.to_id());
+    }
+}
+
+/// Reports `msg` through the diagnostics handler of swc, or logs it if there
+/// is none.
+fn error(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.err(msg));
+    } else {
+        tracing::error!("{}", msg);
+    }
+}
+
+#[plugin_transform]
+pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    let Some(json) = _metadata.get_transform_plugin_config() else {
+        error("lodash: missing config");
+        return program;
+    };
+    // Reported rather than panicking, which would abort the whole compilation.
+    let config = match serde_json::from_str::<Config>(&json) {
+        Ok(config) => config,
+        Err(err) => {
+            error(&format!("lodash: invalid config `{}`: {}", json, err));
+            return program;
+        }
+    };
+
+    program.fold_with(&mut lodash(config))
+}
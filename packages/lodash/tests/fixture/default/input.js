@@ -0,0 +1,6 @@
+import _ from 'lodash-es';
+
+const _debounce = null;
+
+export const save = _.debounce((state) => _.merge({}, state), 100);
+export const flush = () => _.debounce.cancel;
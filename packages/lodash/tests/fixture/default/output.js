@@ -0,0 +1,5 @@
+import _debounce2 from "lodash-es/debounce";
+import _merge from "lodash-es/merge";
+const _debounce = null;
+export const save = _debounce2((state)=>_merge({}, state), 100);
+export const flush = ()=>_debounce2.cancel;
@@ -0,0 +1,4 @@
+import { debounce, merge as deepMerge } from 'lodash';
+import { map } from 'lodash/fp';
+
+export const save = debounce((state) => deepMerge({}, map(String, state)), 100);
@@ -0,0 +1,4 @@
+import debounce from "lodash/debounce";
+import deepMerge from "lodash/merge";
+import map from "lodash/fp/map";
+export const save = debounce((state)=>deepMerge({}, map(String, state)), 100);
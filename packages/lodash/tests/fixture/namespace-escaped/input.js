@@ -0,0 +1,4 @@
+import * as _ from 'lodash';
+
+export const names = _(users).map('name').value();
+export const first = _.head(users);
@@ -0,0 +1,4 @@
+import * as _ from 'lodash';
+import _head from "lodash/head";
+export const names = _(users).map('name').value();
+export const first = _head(users);
@@ -0,0 +1,5 @@
+import R from 'ramda/src';
+import { debounce } from 'lodash';
+
+export const pick = R.pick(['id']);
+export { debounce };
@@ -0,0 +1,6 @@
+import _pick from "ramda/src/pick";
+import { debounce } from 'lodash';
+export const pick = _pick([
+    'id'
+]);
+export { debounce };
@@ -0,0 +1,256 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::mem::take;
+use swc_common::DUMMY_SP;
+use swc_common::errors::HANDLER;
+use swc_core::ecma::{
+    ast::*,
+    atoms::{atom, Atom},
+    visit::{as_folder, noop_visit_mut_type, Fold, FoldWith, VisitMut},
+};
+use swc_plugin_proxy::TransformPluginProgramMetadata;
+use swc_plugin_macro::plugin_transform;
+
+/// Options of modularize-imports, by the name of the package whose imports
+/// are rewritten, like `antd`.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(transparent)]
+pub struct Config {
+    pub packages: BTreeMap<String, PackageConfig>,
+}
+
+/// How the imports of a package are rewritten.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageConfig {
+    /// Path of the module of each member, `{{member}}` standing for its
+    /// transformed name, like `antd/lib/{{member}}`.
+    pub transform: String,
+
+    /// How member names are turned into paths. Defaults to `camel2dash`.
+    #[serde(default)]
+    pub member_transform: MemberTransform,
+
+    /// Path of the stylesheet imported along with each member, like
+    /// `antd/lib/{{member}}/style`.
+    #[serde(default)]
+    pub style: Option<String>,
+
+    /// Import members by name from their module, instead of as its default
+    /// export.
+    #[serde(default)]
+    pub skip_default_conversion: bool,
+}
+
+/// How the name of a member is turned into a path.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum MemberTransform {
+    /// `DatePicker` becomes `date-picker`.
+    #[default]
+    #[serde(rename = "camel2dash")]
+    Camel2Dash,
+    /// `DatePicker` becomes `date_picker`.
+    #[serde(rename = "camel2underscore")]
+    Camel2Underscore,
+    /// `DatePicker` stays `DatePicker`.
+    #[serde(rename = "none")]
+    None,
+}
+
+impl MemberTransform {
+    fn apply(self, name: &str) -> String {
+        let sep = match self {
+            MemberTransform::Camel2Dash => '-',
+            MemberTransform::Camel2Underscore => '_',
+            MemberTransform::None => return name.to_string(),
+        };
+
+        let mut path = String::with_capacity(name.len() + 2);
+        for (i, c) in name.chars().enumerate() {
+            if c.is_ascii_uppercase() {
+                if i > 0 {
+                    path.push(sep);
+                }
+                path.push(c.to_ascii_lowercase());
+            } else {
+                path.push(c);
+            }
+        }
+        path
+    }
+}
+
+impl PackageConfig {
+    /// Returns the path of the module of `member`, and of its stylesheet.
+    fn paths(&self, member: &str) -> (Atom, Option<Atom>) {
+        let name = self.member_transform.apply(member);
+        let path = |template: &str| Atom::from(template.replace("{{member}}", &name));
+
+        (path(&self.transform), self.style.as_deref().map(path))
+    }
+}
+
+/// Rewrites `import { Button } from 'antd'` into
+/// `import Button from 'antd/lib/button'`, like `babel-plugin-import`, so that
+/// only the used members of packages which can't be tree-shaken are bundled.
+pub fn modularize_imports(config: Config) -> impl Fold {
+    as_folder(ModularizeImports { config })
+}
+
+struct ModularizeImports {
+    config: Config,
+}
+
+impl ModularizeImports {
+    /// Takes the members of a configured package out of `import`, returning
+    /// their own imports.
+    fn split_import(&self, import: &mut ImportDecl) -> Vec<ModuleItem> {
+        let Some(package) = self.config.packages.get(&*import.src.value) else {
+            return vec![];
+        };
+        if import.type_only {
+            return vec![];
+        }
+
+        let mut items = vec![];
+        import.specifiers.retain(|s| {
+            // Default and namespace imports need the whole package.
+            let ImportSpecifier::Named(s) = s else {
+                return true;
+            };
+            if s.is_type_only {
+                return true;
+            }
+
+            let member = match &s.imported {
+                Some(imported) => imported.atom().clone(),
+                None => s.local.sym.clone(),
+            };
+            let (path, style) = package.paths(&member);
+            let specifier = if package.skip_default_conversion {
+                ImportSpecifier::Named(s.clone())
+            } else {
+                ImportSpecifier::Default(ImportDefaultSpecifier {
+                    span: s.span,
+                    local: s.local.clone(),
+                })
+            };
+
+            items.push(import_decl(vec![specifier], path));
+            if let Some(style) = style {
+                items.push(import_decl(vec![], style));
+            }
+            false
+        });
+        items
+    }
+
+    /// Takes the members of a configured package out of the re-export `e`,
+    /// returning their own re-exports.
+    fn split_export(&self, e: &mut NamedExport) -> Vec<ModuleItem> {
+        let Some(package) = e.src.as_ref().and_then(|src| self.config.packages.get(&*src.value)) else {
+            return vec![];
+        };
+        if e.type_only {
+            return vec![];
+        }
+
+        let mut items = vec![];
+        e.specifiers.retain(|s| {
+            let ExportSpecifier::Named(s) = s else {
+                return true;
+            };
+            if s.is_type_only {
+                return true;
+            }
+
+            let (path, _) = package.paths(s.orig.atom());
+            let specifier = if package.skip_default_conversion {
+                s.clone()
+            } else {
+                ExportNamedSpecifier {
+                    span: s.span,
+                    orig: ModuleExportName::Ident(Ident::new_no_ctxt(atom!("default"), DUMMY_SP)),
+                    exported: Some(s.exported.clone().unwrap_or_else(|| s.orig.clone())),
+                    is_type_only: false,
+                }
+            };
+
+            items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                span: DUMMY_SP,
+                specifiers: vec![ExportSpecifier::Named(specifier)],
+                src: Some(Box::new(path.into())),
+                type_only: false,
+                with: None,
+            })));
+            false
+        });
+        items
+    }
+}
+
+impl VisitMut for ModularizeImports {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        for mut item in take(items) {
+            let (split, emptied) = match &mut item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                    let split = self.split_import(import);
+                    (split, import.specifiers.is_empty())
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(e)) => {
+                    let split = self.split_export(e);
+                    (split, e.specifiers.is_empty())
+                }
+                _ => (vec![], false),
+            };
+
+            // Dropped once all of its specifiers are rewritten.
+            if split.is_empty() || !emptied {
+                items.push(item);
+            }
+            items.extend(split);
+        }
+    }
+}
+
+/// Creates `import specifiers from 'src'`.
+fn import_decl(specifiers: Vec<ImportSpecifier>, src: Atom) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+        span: DUMMY_SP,
+        specifiers,
+        src: Box::new(src.into()),
+        type_only: false,
+        with: None,
+        phase: Default::default(),
+    }))
+}
+
+/// Reports `msg` through the diagnostics handler of swc, or logs it if there
+/// is none.
+fn error(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.err(msg));
+    } else {
+        tracing::error!("{}", msg);
+    }
+}
+
+#[plugin_transform]
+pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    let Some(json) = _metadata.get_transform_plugin_config() else {
+        error("modularize-imports: missing config");
+        return program;
+    };
+    // Reported rather than panicking, which would abort the whole compilation.
+    let config = match serde_json::from_str::<Config>(&json) {
+        Ok(config) => config,
+        Err(err) => {
+            error(&format!("modularize-imports: invalid config `{}`: {}", json, err));
+            return program;
+        }
+    };
+
+    program.fold_with(&mut modularize_imports(config))
+}
@@ -0,0 +1,5 @@
+import { Button, DatePicker as Picker } from 'antd';
+
+export function App() {
+  return <Picker renderExtraFooter={() => <Button />} />;
+}
@@ -0,0 +1,7 @@
+import Button from "antd/lib/button";
+import "antd/lib/button/style";
+import Picker from "antd/lib/date-picker";
+import "antd/lib/date-picker/style";
+export function App() {
+    return <Picker renderExtraFooter={()=><Button/>}/>;
+}
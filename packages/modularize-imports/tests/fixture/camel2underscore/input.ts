@@ -0,0 +1,4 @@
+import { TimePicker } from '@acme/ui';
+import { ArrowLeft } from 'icons';
+
+console.log(TimePicker, ArrowLeft);
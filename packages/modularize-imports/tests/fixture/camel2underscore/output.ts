@@ -0,0 +1,3 @@
+import TimePicker from "@acme/ui/es/time_picker";
+import ArrowLeft from "icons/ArrowLeft";
+console.log(TimePicker, ArrowLeft);
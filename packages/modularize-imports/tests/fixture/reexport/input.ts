@@ -0,0 +1,3 @@
+export { Button, InputNumber as NumberInput } from 'antd';
+export type { ButtonProps } from 'antd';
+export * from 'antd';
@@ -0,0 +1,4 @@
+export { default as Button } from "antd/es/button";
+export { default as NumberInput } from "antd/es/input-number";
+export type { ButtonProps } from 'antd';
+export * from 'antd';
@@ -0,0 +1,3 @@
+import { format, addDays as add } from 'date-fns';
+
+console.log(format(add(new Date(), 1), 'yyyy'));
@@ -0,0 +1,3 @@
+import { format } from "date-fns/format";
+import { addDays as add } from "date-fns/addDays";
+console.log(format(add(new Date(), 1), 'yyyy'));
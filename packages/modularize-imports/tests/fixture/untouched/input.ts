@@ -0,0 +1,9 @@
+import 'antd/dist/reset.css';
+import antd, { type ButtonProps, Modal } from 'antd';
+import * as all from 'antd';
+import type { TableProps } from 'antd';
+import { Button } from 'antd/lib/button';
+import { useState } from 'react';
+
+export const props: ButtonProps & TableProps<unknown> = {};
+console.log(antd, all, Modal, Button, useState);
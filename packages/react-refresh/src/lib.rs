@@ -0,0 +1,582 @@
+use serde::Deserialize;
+use std::mem::take;
+use swc_common::{collections::AHashSet, util::take::Take, DUMMY_SP};
+use swc_common::errors::HANDLER;
+use swc_core::ecma::{
+    ast::*,
+    atoms::Atom,
+    utils::{collect_decls, quote_ident, ExprFactory},
+    visit::{as_folder, noop_visit_type, noop_visit_mut_type, Fold, FoldWith, Visit, VisitMut, VisitWith},
+};
+use swc_plugin_proxy::TransformPluginProgramMetadata;
+use swc_plugin_macro::plugin_transform;
+
+/// Hooks of React, which don't need to be listed by signatures like custom
+/// hooks.
+const BUILTIN_HOOKS: [&str; 18] = [
+    "use",
+    "useActionState",
+    "useCallback",
+    "useContext",
+    "useDebugValue",
+    "useDeferredValue",
+    "useEffect",
+    "useId",
+    "useImperativeHandle",
+    "useInsertionEffect",
+    "useLayoutEffect",
+    "useMemo",
+    "useOptimistic",
+    "useReducer",
+    "useRef",
+    "useState",
+    "useSyncExternalStore",
+    "useTransition",
+];
+
+/// Options of react-refresh.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Function registering components. Defaults to `$RefreshReg$`.
+    #[serde(default)]
+    pub refresh_reg: Option<String>,
+
+    /// Function creating the signatures of the hooks called by components.
+    /// Defaults to `$RefreshSig$`.
+    #[serde(default)]
+    pub refresh_sig: Option<String>,
+}
+
+/// Registers the top-level components of the module with `$RefreshReg$`, and
+/// signs the functions calling hooks with `$RefreshSig$`, like
+/// `react-refresh/babel`, so their state is kept across fast refreshes unless
+/// their hooks change.
+pub fn react_refresh(config: Config) -> impl Fold {
+    as_folder(ReactRefresh {
+        config,
+        registrations: vec![],
+        declared: Default::default(),
+        taken: Default::default(),
+    })
+}
+
+struct ReactRefresh {
+    config: Config,
+    /// Handles of the registered components, and their names.
+    registrations: Vec<(Ident, String)>,
+    /// Bindings of the module, which the handles and signatures must not
+    /// shadow.
+    declared: AHashSet<Id>,
+    /// Names of the handles and signatures created so far.
+    taken: AHashSet<String>,
+}
+
+impl ReactRefresh {
+    /// Returns a new binding named after `prefix`, like `_c` or `_c2` if `_c`
+    /// is taken.
+    fn unique(&mut self, prefix: &str) -> Ident {
+        let mut name = prefix.to_string();
+        let mut n = 1;
+        while self.declared.iter().any(|id| *id.0 == name) || self.taken.contains(&name) {
+            n += 1;
+            name = format!("{}{}", prefix, n);
+        }
+
+        self.taken.insert(name.clone());
+        quote_ident!(name).into()
+    }
+
+    /// Returns a new handle for the component named `name`, like `_c2`.
+    fn register(&mut self, name: String) -> Ident {
+        let handle = self.unique("_c");
+        self.registrations.push((handle.clone(), name));
+        handle
+    }
+
+    /// Signs `function` if it calls hooks, returning the signature, the
+    /// statement creating it and its arguments after the function itself.
+    fn sign(&mut self, function: &mut dyn Signable) -> Option<(Ident, Stmt, Vec<ExprOrSpread>)> {
+        let hooks = function.hooks()?;
+        let signature = self.unique("_s");
+
+        function.prepend(signature.clone().as_call(DUMMY_SP, vec![]).into_stmt());
+
+        let refresh_sig = self.config.refresh_sig.as_deref().unwrap_or("$RefreshSig$");
+        let create = VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Var,
+            declare: false,
+            decls: vec![VarDeclarator {
+                span: DUMMY_SP,
+                name: signature.clone().into(),
+                init: Some(Box::new(quote_ident!(refresh_sig).as_call(DUMMY_SP, vec![]))),
+                definite: false,
+            }],
+            ..Take::dummy()
+        };
+
+        let key = hooks.iter().map(|h| h.key.as_str()).collect::<Vec<_>>().join("\n");
+        let mut args = vec![Expr::Lit(Lit::Str(key.into())).as_arg()];
+        let custom: Vec<_> = hooks.into_iter().filter_map(|h| h.custom).collect();
+        if !custom.is_empty() {
+            // Lets the signature tell whether custom hooks changed too.
+            let getter = Function {
+                body: Some(BlockStmt {
+                    stmts: vec![Stmt::Return(ReturnStmt {
+                        span: DUMMY_SP,
+                        arg: Some(Box::new(Expr::Array(ArrayLit {
+                            span: DUMMY_SP,
+                            elems: custom.into_iter().map(|c| Some(c.as_arg())).collect(),
+                        }))),
+                    })],
+                    ..Take::dummy()
+                }),
+                ..Take::dummy()
+            };
+            args.push(Expr::Lit(Lit::Bool(false.into())).as_arg());
+            args.push(Expr::Fn(FnExpr { ident: None, function: Box::new(getter) }).as_arg());
+        }
+
+        Some((signature, create.into(), args))
+    }
+
+    /// Registers and signs the components and hooks declared by `item`,
+    /// returning the statements to insert before and after it.
+    fn process_item(&mut self, item: &mut ModuleItem) -> (Vec<Stmt>, Vec<Stmt>) {
+        let (mut before, mut after) = (vec![], vec![]);
+
+        let function = match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl { ident, function, .. })))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Fn(FnDecl { ident, function, .. }),
+                ..
+            }))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::Fn(FnExpr {
+                    ident: Some(ident),
+                    function,
+                }),
+                ..
+            })) => Some((ident.clone(), &mut **function)),
+            _ => None,
+        };
+        if let Some((ident, function)) = function {
+            if let Some((signature, create, mut args)) = self.sign(function) {
+                before.push(create);
+                args.insert(0, ident.clone().as_arg());
+                after.push(signature.as_call(DUMMY_SP, args).into_stmt());
+            }
+            if is_component_name(&ident.sym) {
+                let handle = self.register(ident.sym.to_string());
+                after.push(assign(handle, ident.into()).into_stmt());
+            }
+            return (before, after);
+        }
+
+        if let ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+            span,
+            decl: DefaultDecl::Fn(FnExpr { ident: None, function }),
+        })) = item
+        {
+            // Turned into an expression to be assigned to its handle.
+            *item = ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                span: *span,
+                expr: Box::new(Expr::Fn(FnExpr {
+                    ident: None,
+                    function: function.take(),
+                })),
+            }));
+        }
+        if let ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { expr, .. })) = item {
+            self.register_default(expr, &mut before);
+            return (before, after);
+        }
+
+        let (ModuleItem::Stmt(Stmt::Decl(Decl::Var(var)))
+        | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            decl: Decl::Var(var), ..
+        }))) = item
+        else {
+            return (before, after);
+        };
+        for d in &mut var.decls {
+            let (Pat::Ident(BindingIdent { id, .. }), Some(init)) = (&d.name, &mut d.init) else {
+                continue;
+            };
+
+            match &mut **init {
+                Expr::Arrow(..) | Expr::Fn(..) => {
+                    let signable: &mut dyn Signable = match &mut **init {
+                        Expr::Arrow(arrow) => arrow,
+                        Expr::Fn(FnExpr { function, .. }) => &mut **function,
+                        _ => unreachable!(),
+                    };
+                    if let Some((signature, create, mut args)) = self.sign(signable) {
+                        before.push(create);
+                        args.insert(0, id.clone().as_arg());
+                        after.push(signature.as_call(DUMMY_SP, args).into_stmt());
+                    }
+                    if is_component_name(&id.sym) {
+                        let handle = self.register(id.sym.to_string());
+                        after.push(assign(handle, id.clone().into()).into_stmt());
+                    }
+                }
+                Expr::Call(call) if is_component_name(&id.sym) => {
+                    if self.register_hoc(call, id.sym.to_string(), &mut before) {
+                        let handle = self.register(id.sym.to_string());
+                        after.push(assign(handle, id.clone().into()).into_stmt());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        (before, after)
+    }
+
+    /// Registers the anonymous component exported by default as `%default%`,
+    /// like `export default () => ...`, or the one passed inline to the
+    /// higher-order component it calls as `%default%$memo`.
+    fn register_default(&mut self, expr: &mut Box<Expr>, before: &mut Vec<Stmt>) {
+        match &mut **expr {
+            Expr::Call(call) => {
+                if self.register_hoc(call, "%default%".to_string(), before) {
+                    let handle = self.register("%default%".to_string());
+                    *expr = Box::new(assign(handle, expr.take()));
+                }
+            }
+            Expr::Arrow(..) | Expr::Fn(FnExpr { ident: None, .. }) => {
+                let signable: &mut dyn Signable = match &mut **expr {
+                    Expr::Arrow(arrow) => arrow,
+                    Expr::Fn(FnExpr { function, .. }) => &mut **function,
+                    _ => unreachable!(),
+                };
+                if let Some((signature, create, mut args)) = self.sign(signable) {
+                    before.push(create);
+                    args.insert(0, expr.take().as_arg());
+                    *expr = Box::new(signature.as_call(DUMMY_SP, args));
+                }
+                let handle = self.register("%default%".to_string());
+                *expr = Box::new(assign(handle, expr.take()));
+            }
+            _ => {}
+        }
+    }
+
+    /// Registers the component passed inline to the higher-order component
+    /// `call`, like the arrow function of `memo(() => ...)` as `Name$memo`,
+    /// returning whether there was one.
+    fn register_hoc(&mut self, call: &mut CallExpr, name: String, before: &mut Vec<Stmt>) -> bool {
+        let Callee::Expr(callee) = &call.callee else {
+            return false;
+        };
+        let name = match &**callee {
+            Expr::Ident(i) => format!("{}${}", name, i.sym),
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => match &**obj {
+                Expr::Ident(obj) => format!("{}${}.{}", name, obj.sym, prop.sym),
+                _ => return false,
+            },
+            _ => return false,
+        };
+        let Some(ExprOrSpread { spread: None, expr }) = call.args.first_mut() else {
+            return false;
+        };
+
+        match &mut **expr {
+            Expr::Call(inner) => {
+                if !self.register_hoc(inner, name.clone(), before) {
+                    return false;
+                }
+            }
+            Expr::Arrow(..) | Expr::Fn(..) => {
+                let signable: &mut dyn Signable = match &mut **expr {
+                    Expr::Arrow(arrow) => arrow,
+                    Expr::Fn(FnExpr { function, .. }) => &mut **function,
+                    _ => unreachable!(),
+                };
+                if let Some((signature, create, mut args)) = self.sign(signable) {
+                    before.push(create);
+                    args.insert(0, expr.take().as_arg());
+                    *expr = Box::new(signature.as_call(DUMMY_SP, args));
+                }
+            }
+            _ => return false,
+        }
+
+        let handle = self.register(name);
+        *expr = Box::new(assign(handle, expr.take()));
+        true
+    }
+}
+
+impl VisitMut for ReactRefresh {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        self.declared = collect_decls(&*m);
+
+        for mut item in take(&mut m.body) {
+            let (before, after) = self.process_item(&mut item);
+            m.body.extend(before.into_iter().map(ModuleItem::Stmt));
+            m.body.push(item);
+            m.body.extend(after.into_iter().map(ModuleItem::Stmt));
+        }
+
+        if self.registrations.is_empty() {
+            return;
+        }
+        let handles = VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Var,
+            declare: false,
+            decls: self
+                .registrations
+                .iter()
+                .map(|(handle, _)| VarDeclarator {
+                    span: DUMMY_SP,
+                    name: handle.clone().into(),
+                    init: None,
+                    definite: false,
+                })
+                .collect(),
+            ..Take::dummy()
+        };
+        m.body.push(ModuleItem::Stmt(handles.into()));
+
+        let refresh_reg = self.config.refresh_reg.as_deref().unwrap_or("$RefreshReg$");
+        for (handle, name) in take(&mut self.registrations) {
+            let register = quote_ident!(refresh_reg).as_call(
+                DUMMY_SP,
+                vec![handle.as_arg(), Expr::Lit(Lit::Str(name.into())).as_arg()],
+            );
+            m.body.push(ModuleItem::Stmt(register.into_stmt()));
+        }
+    }
+}
+
+/// Functions which can be signed.
+trait Signable {
+    /// Returns the hooks called by the function, if any.
+    fn hooks(&self) -> Option<Vec<Hook>>;
+
+    /// Inserts `stmt` at the start of the body of the function.
+    fn prepend(&mut self, stmt: Stmt);
+}
+
+impl Signable for Function {
+    fn hooks(&self) -> Option<Vec<Hook>> {
+        hooks(self.body.as_ref()?)
+    }
+
+    fn prepend(&mut self, stmt: Stmt) {
+        if let Some(body) = &mut self.body {
+            body.stmts.insert(0, stmt);
+        }
+    }
+}
+
+impl Signable for ArrowExpr {
+    fn hooks(&self) -> Option<Vec<Hook>> {
+        hooks(&*self.body)
+    }
+
+    fn prepend(&mut self, stmt: Stmt) {
+        let body = match &mut *self.body {
+            BlockStmtOrExpr::BlockStmt(body) => body,
+            BlockStmtOrExpr::Expr(expr) => {
+                let ret = Stmt::Return(ReturnStmt {
+                    span: DUMMY_SP,
+                    arg: Some(expr.take()),
+                });
+                *self.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    stmts: vec![ret],
+                    ..Take::dummy()
+                });
+                let BlockStmtOrExpr::BlockStmt(body) = &mut *self.body else {
+                    unreachable!()
+                };
+                body
+            }
+        };
+        body.stmts.insert(0, stmt);
+    }
+}
+
+/// A call of a hook.
+struct Hook {
+    /// Part of the signature, like `useState{[count, setCount](0)}`.
+    key: String,
+    /// The callee of a custom hook.
+    custom: Option<Box<Expr>>,
+}
+
+/// Returns the hooks called by the body `n` of a function, outside of
+/// nested functions, if any.
+fn hooks<N: VisitWith<HookCollector>>(n: &N) -> Option<Vec<Hook>> {
+    let mut collector = HookCollector { hooks: vec![] };
+    n.visit_with(&mut collector);
+    Some(collector.hooks).filter(|hooks| !hooks.is_empty())
+}
+
+struct HookCollector {
+    hooks: Vec<Hook>,
+}
+
+impl HookCollector {
+    /// Records `call` if it calls a hook, with the pattern `lhs` it
+    /// initializes. Returns whether it did.
+    fn record(&mut self, call: &CallExpr, lhs: &str) -> bool {
+        let Callee::Expr(callee) = &call.callee else {
+            return false;
+        };
+        let name = match &**callee {
+            Expr::Ident(i) => &i.sym,
+            Expr::Member(MemberExpr {
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => &prop.sym,
+            _ => return false,
+        };
+        if !is_hook_name(name) {
+            return false;
+        }
+
+        // Changing the initial state resets it too.
+        let mut arg = String::new();
+        if name == "useState" || name == "useReducer" {
+            if let Some(Expr::Lit(lit)) = call.args.first().map(|a| &*a.expr) {
+                arg = format!("({})", lit_source(lit));
+            }
+        }
+
+        self.hooks.push(Hook {
+            key: format!("{}{{{}{}}}", name, lhs, arg),
+            custom: (!BUILTIN_HOOKS.contains(&&**name)).then(|| callee.clone()),
+        });
+        true
+    }
+}
+
+impl Visit for HookCollector {
+    noop_visit_type!();
+
+    fn visit_var_declarator(&mut self, d: &VarDeclarator) {
+        if let Some(Expr::Call(call)) = d.init.as_deref() {
+            if self.record(call, &pat_source(&d.name)) {
+                call.args.visit_with(self);
+                return;
+            }
+        }
+        d.visit_children_with(self);
+    }
+
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        self.record(call, "");
+        call.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_class(&mut self, _: &Class) {}
+}
+
+/// Whether `name` is the name of a component: capitalized, as React requires.
+fn is_component_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+/// Whether `name` is the name of a hook, like `useState` or `use`.
+fn is_hook_name(name: &str) -> bool {
+    name == "use" || name.strip_prefix("use").is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+}
+
+/// Creates `left = right`.
+fn assign(left: Ident, right: Box<Expr>) -> Expr {
+    Expr::Assign(AssignExpr {
+        span: DUMMY_SP,
+        op: op!("="),
+        left: left.into(),
+        right,
+    })
+}
+
+/// Returns the source of the pattern `pat`, like `[count, setCount]`.
+fn pat_source(pat: &Pat) -> String {
+    match pat {
+        Pat::Ident(i) => i.sym.to_string(),
+        Pat::Array(ArrayPat { elems, .. }) => {
+            let elems: Vec<_> = elems.iter().map(|e| e.as_ref().map(pat_source).unwrap_or_default()).collect();
+            format!("[{}]", elems.join(", "))
+        }
+        Pat::Object(ObjectPat { props, .. }) => {
+            let props: Vec<_> = props
+                .iter()
+                .map(|p| match p {
+                    ObjectPatProp::KeyValue(KeyValuePatProp { key, value }) => {
+                        format!("{}: {}", prop_name_source(key), pat_source(value))
+                    }
+                    ObjectPatProp::Assign(AssignPatProp { key, .. }) => key.sym.to_string(),
+                    ObjectPatProp::Rest(RestPat { arg, .. }) => format!("...{}", pat_source(arg)),
+                })
+                .collect();
+            format!("{{{}}}", props.join(", "))
+        }
+        Pat::Assign(AssignPat { left, .. }) => pat_source(left),
+        Pat::Rest(RestPat { arg, .. }) => format!("...{}", pat_source(arg)),
+        _ => String::new(),
+    }
+}
+
+fn prop_name_source(key: &PropName) -> String {
+    match key {
+        PropName::Ident(i) => i.sym.to_string(),
+        PropName::Str(s) => format!("{:?}", &*s.value),
+        PropName::Num(n) => n.value.to_string(),
+        _ => String::new(),
+    }
+}
+
+fn lit_source(lit: &Lit) -> String {
+    match lit {
+        Lit::Str(s) => s.raw.as_ref().map_or_else(|| format!("{:?}", &*s.value), Atom::to_string),
+        Lit::Num(n) => n.raw.as_ref().map_or_else(|| n.value.to_string(), Atom::to_string),
+        Lit::Bool(b) => b.value.to_string(),
+        Lit::Null(..) => "null".to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Reports `msg` through the diagnostics handler of swc, or logs it if there
+/// is none.
+fn error(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.err(msg));
+    } else {
+        tracing::error!("{}", msg);
+    }
+}
+
+#[plugin_transform]
+pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    let Some(json) = _metadata.get_transform_plugin_config() else {
+        error("react-refresh: missing config");
+        return program;
+    };
+    // Reported rather than panicking, which would abort the whole compilation.
+    let config = match serde_json::from_str::<Config>(&json) {
+        Ok(config) => config,
+        Err(err) => {
+            error(&format!("react-refresh: invalid config `{}`: {}", json, err));
+            return program;
+        }
+    };
+
+    program.fold_with(&mut react_refresh(config))
+}
@@ -0,0 +1,14 @@
+import { useState } from 'react';
+
+const _c = 'card';
+function _s2() {}
+
+export function Card() {
+  const [open] = useState(false);
+  return <div className={_c} />;
+}
+
+export function List() {
+  const [items] = useState([]);
+  return <Card />;
+}
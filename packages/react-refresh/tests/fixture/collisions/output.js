@@ -0,0 +1,22 @@
+import { useState } from 'react';
+const _c = 'card';
+function _s2() {}
+var _s = $RefreshSig$();
+export function Card() {
+    _s();
+    const [open] = useState(false);
+    return <div className={_c}/>;
+}
+_s(Card, "useState{[open](false)}");
+_c2 = Card;
+var _s3 = $RefreshSig$();
+export function List() {
+    _s3();
+    const [items] = useState([]);
+    return <Card/>;
+}
+_s3(List, "useState{[items]}");
+_c3 = List;
+var _c2, _c3;
+$RefreshReg$(_c2, "Card");
+$RefreshReg$(_c3, "List");
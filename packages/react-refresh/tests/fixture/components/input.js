@@ -0,0 +1,19 @@
+export function App() {
+  return <Header title="Hello" />;
+}
+
+const Header = ({ title }) => <h1>{title}</h1>;
+
+export default function Page() {
+  return <App />;
+}
+
+const Footer = function () {
+  return <footer />;
+};
+
+function helper() {
+  return 1;
+}
+
+const config = {};
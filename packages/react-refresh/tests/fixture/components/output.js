@@ -0,0 +1,23 @@
+export function App() {
+    return <Header title="Hello"/>;
+}
+_c = App;
+const Header = ({ title })=><h1>{title}</h1>;
+_c2 = Header;
+export default function Page() {
+    return <App/>;
+}
+_c3 = Page;
+const Footer = function() {
+    return <footer/>;
+};
+_c4 = Footer;
+function helper() {
+    return 1;
+}
+const config = {};
+var _c, _c2, _c3, _c4;
+$RefreshReg$(_c, "App");
+$RefreshReg$(_c2, "Header");
+$RefreshReg$(_c3, "Page");
+$RefreshReg$(_c4, "Footer");
@@ -0,0 +1,6 @@
+import { useState } from 'react';
+
+export default () => {
+  const [open, setOpen] = useState(false);
+  return <dialog open={open} />;
+};
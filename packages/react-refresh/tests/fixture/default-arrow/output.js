@@ -0,0 +1,9 @@
+import { useState } from 'react';
+var _s = $RefreshSig$();
+export default _c = _s(()=>{
+    _s();
+    const [open, setOpen] = useState(false);
+    return <dialog open={open}/>;
+}, "useState{[open, setOpen](false)}");
+var _c;
+$RefreshReg$(_c, "%default%");
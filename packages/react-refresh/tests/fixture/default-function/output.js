@@ -0,0 +1,5 @@
+export default _c = function() {
+    return <main/>;
+};
+var _c;
+$RefreshReg$(_c, "%default%");
@@ -0,0 +1,3 @@
+import { memo } from 'react';
+
+export default memo(() => <aside />);
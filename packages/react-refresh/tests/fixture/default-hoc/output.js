@@ -0,0 +1,5 @@
+import { memo } from 'react';
+export default _c2 = memo(_c = ()=><aside/>);
+var _c, _c2;
+$RefreshReg$(_c, "%default%$memo");
+$RefreshReg$(_c2, "%default%");
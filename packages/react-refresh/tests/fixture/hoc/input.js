@@ -0,0 +1,12 @@
+import React, { memo, forwardRef } from 'react';
+
+export const Input = memo(forwardRef((props, ref) => {
+  const [value] = useState('');
+  return <input ref={ref} value={value} />;
+}));
+
+const Panel = React.memo(function Panel() {
+  return <div />;
+});
+
+const Store = createStore({});
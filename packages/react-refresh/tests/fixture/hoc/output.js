@@ -0,0 +1,19 @@
+import React, { memo, forwardRef } from 'react';
+var _s = $RefreshSig$();
+export const Input = memo(_c2 = forwardRef(_c = _s((props, ref)=>{
+    _s();
+    const [value] = useState('');
+    return <input ref={ref} value={value}/>;
+}, "useState{[value]('')}")));
+_c3 = Input;
+const Panel = React.memo(_c4 = function Panel() {
+    return <div/>;
+});
+_c5 = Panel;
+const Store = createStore({});
+var _c, _c2, _c3, _c4, _c5;
+$RefreshReg$(_c, "Input$memo$forwardRef");
+$RefreshReg$(_c2, "Input$memo");
+$RefreshReg$(_c3, "Input");
+$RefreshReg$(_c4, "Panel$React.memo");
+$RefreshReg$(_c5, "Panel");
@@ -0,0 +1,16 @@
+import { useState, useEffect } from 'react';
+import { useStore } from './store';
+
+export default function Counter() {
+  const [count, setCount] = useState(0);
+  const { user } = useStore();
+  useEffect(() => {
+    document.title = `${count}`;
+  }, [count]);
+  const onClick = () => {
+    const ignored = useState(1);
+  };
+  return <button onClick={() => setCount(count + 1)}>{user}</button>;
+}
+
+export const useToggle = () => React.useState(false);
@@ -0,0 +1,31 @@
+import { useState, useEffect } from 'react';
+import { useStore } from './store';
+var _s = $RefreshSig$();
+export default function Counter() {
+    _s();
+    const [count, setCount] = useState(0);
+    const { user } = useStore();
+    useEffect(()=>{
+        document.title = `${count}`;
+    }, [
+        count
+    ]);
+    const onClick = ()=>{
+        const ignored = useState(1);
+    };
+    return <button onClick={()=>setCount(count + 1)}>{user}</button>;
+}
+_s(Counter, "useState{[count, setCount](0)}\nuseStore{{user}}\nuseEffect{}", false, function() {
+    return [
+        useStore
+    ];
+});
+_c = Counter;
+var _s2 = $RefreshSig$();
+export const useToggle = ()=>{
+    _s2();
+    return React.useState(false);
+};
+_s2(useToggle, "useState{(false)}");
+var _c;
+$RefreshReg$(_c, "Counter");
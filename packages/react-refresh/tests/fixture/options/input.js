@@ -0,0 +1,4 @@
+export function App() {
+  const [open] = useState(false);
+  return <div />;
+}
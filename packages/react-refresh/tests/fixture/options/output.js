@@ -0,0 +1,10 @@
+var _s = __signature__();
+export function App() {
+    _s();
+    const [open] = useState(false);
+    return <div/>;
+}
+_s(App, "useState{[open](false)}");
+_c = App;
+var _c;
+__register__(_c, "App");
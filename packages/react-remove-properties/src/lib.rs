@@ -0,0 +1,193 @@
+use glob::Pattern;
+use serde::Deserialize;
+use swc_common::collections::AHashSet;
+use swc_common::errors::HANDLER;
+use swc_core::ecma::{
+    ast::*,
+    visit::{as_folder, noop_visit_mut_type, Fold, FoldWith, VisitMut, VisitMutWith},
+};
+use swc_plugin_proxy::TransformPluginProgramMetadata;
+use swc_plugin_macro::plugin_transform;
+
+/// Names of the removed properties if none are configured.
+const DEFAULT_PROPERTIES: [&str; 2] = ["data-testid", "data-test-*"];
+
+/// Options of react-remove-properties.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Glob patterns of the names of the properties to remove. Defaults to
+    /// `data-testid` and `data-test-*`.
+    #[serde(default)]
+    pub properties: Option<Vec<String>>,
+}
+
+/// Removes the matching JSX attributes, and the matching properties of the
+/// objects passed to `React.createElement` and the `jsx` functions of React.
+pub fn react_remove_properties(config: Config) -> impl Fold {
+    let properties = match &config.properties {
+        Some(properties) => properties
+            .iter()
+            .filter_map(|p| match Pattern::new(p) {
+                Ok(p) => Some(p),
+                Err(err) => {
+                    tracing::warn!("react_remove_properties: invalid pattern `{}`: {}", p, err);
+                    None
+                }
+            })
+            .collect(),
+        None => DEFAULT_PROPERTIES.iter().map(|p| Pattern::new(p).unwrap()).collect(),
+    };
+
+    as_folder(ReactRemoveProperties {
+        properties,
+        react: Default::default(),
+        factories: Default::default(),
+    })
+}
+
+struct ReactRemoveProperties {
+    properties: Vec<Pattern>,
+    /// Default and namespace imports of `react`.
+    react: AHashSet<Id>,
+    /// Imports of `createElement` from `react`, and of the `jsx` functions
+    /// from its JSX runtimes.
+    factories: AHashSet<Id>,
+}
+
+impl ReactRemoveProperties {
+    fn removes(&self, name: &str) -> bool {
+        self.properties.iter().any(|p| p.matches(name))
+    }
+
+    /// Whether `callee` creates elements from the properties of its second
+    /// argument.
+    fn is_factory(&self, callee: &Expr) -> bool {
+        match callee {
+            Expr::Ident(i) => self.factories.contains(&i.to_id()),
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => prop.sym == "createElement" && matches!(&**obj, Expr::Ident(i) if self.react.contains(&i.to_id())),
+            _ => false,
+        }
+    }
+
+    /// Collects the imports of React from `items`.
+    fn collect_imports(&mut self, items: &[ModuleItem]) {
+        for item in items {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+                continue;
+            };
+            let factories: &[&str] = match &*import.src.value {
+                "react" => &["createElement"],
+                "react/jsx-runtime" => &["jsx", "jsxs"],
+                "react/jsx-dev-runtime" => &["jsxDEV"],
+                _ => continue,
+            };
+
+            for s in &import.specifiers {
+                match s {
+                    ImportSpecifier::Named(ImportNamedSpecifier { local, imported, .. }) => {
+                        let imported = imported.as_ref().map_or(&local.sym, |i| i.atom());
+                        if factories.contains(&&**imported) {
+                            self.factories.insert(local.to_id());
+                        }
+                    }
+                    ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                    | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                        if &*import.src.value == "react" {
+                            self.react.insert(local.to_id());
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl VisitMut for ReactRemoveProperties {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        self.collect_imports(&m.body);
+        m.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_jsx_opening_element(&mut self, e: &mut JSXOpeningElement) {
+        e.attrs.retain(|attr| match attr {
+            JSXAttrOrSpread::JSXAttr(JSXAttr { name, .. }) => {
+                let name = match name {
+                    JSXAttrName::Ident(i) => i.sym.to_string(),
+                    JSXAttrName::JSXNamespacedName(n) => format!("{}:{}", n.ns.sym, n.name.sym),
+                };
+                !self.removes(&name)
+            }
+            JSXAttrOrSpread::SpreadElement(..) => true,
+        });
+
+        e.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_call_expr(&mut self, call: &mut CallExpr) {
+        call.visit_mut_children_with(self);
+
+        let Callee::Expr(callee) = &call.callee else {
+            return;
+        };
+        if !self.is_factory(callee) {
+            return;
+        }
+        let Some(ExprOrSpread { spread: None, expr }) = call.args.get_mut(1) else {
+            return;
+        };
+        let Expr::Object(props) = &mut **expr else {
+            return;
+        };
+
+        props.props.retain(|p| {
+            let name = match p {
+                PropOrSpread::Prop(p) => match &**p {
+                    Prop::Shorthand(i) => &i.sym,
+                    Prop::KeyValue(KeyValueProp { key, .. }) => match key {
+                        PropName::Ident(i) => &i.sym,
+                        PropName::Str(s) => &s.value,
+                        _ => return true,
+                    },
+                    _ => return true,
+                },
+                PropOrSpread::Spread(..) => return true,
+            };
+            !self.removes(name)
+        });
+    }
+}
+
+/// Reports `msg` through the diagnostics handler of swc, or logs it if there
+/// is none.
+fn error(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.err(msg));
+    } else {
+        tracing::error!("{}", msg);
+    }
+}
+
+#[plugin_transform]
+pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    let Some(json) = _metadata.get_transform_plugin_config() else {
+        error("react-remove-properties: missing config");
+        return program;
+    };
+    // Reported rather than panicking, which would abort the whole compilation.
+    let config = match serde_json::from_str::<Config>(&json) {
+        Ok(config) => config,
+        Err(err) => {
+            error(&format!("react-remove-properties: invalid config `{}`: {}", json, err));
+            return program;
+        }
+    };
+
+    program.fold_with(&mut react_remove_properties(config))
+}
@@ -0,0 +1,7 @@
+export function Button({ label, ...props }) {
+  return (
+    <button data-testid="button" data-test-kind="primary" data-id="1" {...props}>
+      <span data-test-label>{label}</span>
+    </button>
+  );
+}
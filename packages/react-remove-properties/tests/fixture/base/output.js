@@ -0,0 +1,5 @@
+export function Button({ label, ...props }) {
+    return <button data-id="1" {...props}>
+      <span>{label}</span>
+    </button>;
+}
@@ -0,0 +1,7 @@
+import React from 'react';
+import { jsx as _jsx } from 'react/jsx-runtime';
+import { createElement } from './custom';
+
+export const a = React.createElement('div', { 'data-testid': 'a', id: 'a' });
+export const b = _jsx('div', { 'data-test-id': 'b', children: 'b' });
+export const c = createElement('div', { 'data-testid': 'c' });
@@ -0,0 +1,12 @@
+import React from 'react';
+import { jsx as _jsx } from 'react/jsx-runtime';
+import { createElement } from './custom';
+export const a = React.createElement('div', {
+    id: 'a'
+});
+export const b = _jsx('div', {
+    children: 'b'
+});
+export const c = createElement('div', {
+    'data-testid': 'c'
+});
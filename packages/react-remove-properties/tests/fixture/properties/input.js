@@ -0,0 +1 @@
+export const Link = () => <a data-qa="link" e2eId="link" data-testid="link" href="/" />;
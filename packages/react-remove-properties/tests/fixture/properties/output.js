@@ -0,0 +1 @@
+export const Link = ()=><a data-testid="link" href="/"/>;
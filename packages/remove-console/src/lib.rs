@@ -0,0 +1,188 @@
+use regex::Regex;
+use serde::Deserialize;
+use swc_common::DUMMY_SP;
+use swc_common::errors::HANDLER;
+use swc_core::ecma::{
+    ast::*,
+    utils::{collect_decls, BindingCollector},
+    visit::{as_folder, noop_visit_mut_type, Fold, FoldWith, VisitMut, VisitMutWith, VisitWith},
+};
+use swc_plugin_proxy::TransformPluginProgramMetadata;
+use swc_plugin_macro::plugin_transform;
+
+/// Options of remove-console.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Methods whose calls are kept, like `error` and `warn`.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+
+    /// Regular expression the first argument of a call must match for it to be
+    /// removed, like `^\[debug\]`. Calls whose first argument isn't a string
+    /// are kept if set.
+    #[serde(default)]
+    pub argument_pattern: Option<String>,
+
+    /// Replace the calls used as values, like `() => console.log(x)`, with
+    /// `void 0`. Otherwise only the calls used as statements are removed.
+    #[serde(default)]
+    pub replace_with_void: bool,
+}
+
+/// Removes the calls of the methods of `console`.
+pub fn remove_console(config: Config) -> impl Fold {
+    let argument_pattern = config
+        .argument_pattern
+        .as_deref()
+        .map(|pattern| match Regex::new(pattern) {
+            Ok(re) => Some(re),
+            Err(err) => {
+                // Matches nothing rather than everything.
+                tracing::warn!("remove_console: invalid pattern `{}`: {}", pattern, err);
+                None
+            }
+        });
+
+    as_folder(RemoveConsole {
+        config,
+        argument_pattern,
+        shadowed: false,
+    })
+}
+
+struct RemoveConsole {
+    config: Config,
+    /// `None` for an invalid pattern.
+    argument_pattern: Option<Option<Regex>>,
+    /// Whether the module declares its own `console`.
+    shadowed: bool,
+}
+
+impl RemoveConsole {
+    /// Whether `e` is a call to remove.
+    fn removes(&self, e: &Expr) -> bool {
+        let Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) = e
+        else {
+            return false;
+        };
+        let Expr::Member(MemberExpr { obj, prop, .. }) = &**callee else {
+            return false;
+        };
+        if !matches!(&**obj, Expr::Ident(i) if i.sym == "console") || self.shadowed {
+            return false;
+        }
+
+        let method = match prop {
+            MemberProp::Ident(i) => &i.sym,
+            MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
+                Expr::Lit(Lit::Str(s)) => &s.value,
+                _ => return false,
+            },
+            MemberProp::PrivateName(..) => return false,
+        };
+        if self.config.exclude.iter().any(|m| m == &**method) {
+            return false;
+        }
+
+        match &self.argument_pattern {
+            None => true,
+            Some(None) => false,
+            Some(Some(re)) => args.first().and_then(|arg| string_value(&arg.expr)).is_some_and(|s| re.is_match(s)),
+        }
+    }
+}
+
+impl VisitMut for RemoveConsole {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        self.shadowed = declares_console(&*m);
+        m.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        self.shadowed = declares_console(&*s);
+        s.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.visit_mut_children_with(self);
+        items.retain(|i| !matches!(i, ModuleItem::Stmt(Stmt::Empty(..))));
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.visit_mut_children_with(self);
+        stmts.retain(|s| !matches!(s, Stmt::Empty(..)));
+    }
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        if let Stmt::Expr(ExprStmt { span, expr }) = s {
+            if self.removes(expr) {
+                *s = Stmt::Empty(EmptyStmt { span: *span });
+                return;
+            }
+        }
+
+        s.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        if self.config.replace_with_void && self.removes(e) {
+            *e = Expr::Unary(UnaryExpr {
+                span: DUMMY_SP,
+                op: op!("void"),
+                arg: 0.into(),
+            });
+        }
+    }
+}
+
+/// Whether `n` declares a binding named `console`.
+fn declares_console<N: VisitWith<BindingCollector<Id>>>(n: &N) -> bool {
+    collect_decls::<Id, _>(n).iter().any(|id| id.0 == "console")
+}
+
+/// Returns the value of the string literal or the template literal without
+/// expressions `e`.
+fn string_value(e: &Expr) -> Option<&str> {
+    match e {
+        Expr::Lit(Lit::Str(s)) => Some(&s.value),
+        Expr::Tpl(Tpl { exprs, quasis, .. }) if exprs.is_empty() => quasis.first().and_then(|q| q.cooked.as_deref()),
+        _ => None,
+    }
+}
+
+/// Reports `msg` through the diagnostics handler of swc, or logs it if there
+/// is none.
+fn error(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.err(msg));
+    } else {
+        tracing::error!("{}", msg);
+    }
+}
+
+#[plugin_transform]
+pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    let Some(json) = _metadata.get_transform_plugin_config() else {
+        error("remove-console: missing config");
+        return program;
+    };
+    // Reported rather than panicking, which would abort the whole compilation.
+    let config = match serde_json::from_str::<Config>(&json) {
+        Ok(config) => config,
+        Err(err) => {
+            error(&format!("remove-console: invalid config `{}`: {}", json, err));
+            return program;
+        }
+    };
+
+    program.fold_with(&mut remove_console(config))
+}
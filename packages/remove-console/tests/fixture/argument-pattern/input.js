@@ -0,0 +1,4 @@
+console.log('[debug] props', props);
+console.log(`[debug] state`);
+console.log('[info] ready');
+console.log(props);
@@ -0,0 +1,2 @@
+console.log('[info] ready');
+console.log(props);
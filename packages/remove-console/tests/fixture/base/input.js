@@ -0,0 +1,8 @@
+console.log('render', props);
+console['debug']('state');
+
+export function update(state) {
+  console.info('update');
+  if (state.dirty) console.warn('dirty');
+  return () => console.log('cleanup');
+}
@@ -0,0 +1,4 @@
+export function update(state) {
+    if (state.dirty) ;
+    return ()=>console.log('cleanup');
+}
@@ -0,0 +1,9 @@
+console.log('loaded');
+console.warn('deprecated');
+
+try {
+  run();
+} catch (err) {
+  console.error(err);
+  console.trace();
+}
@@ -0,0 +1,6 @@
+console.warn('deprecated');
+try {
+    run();
+} catch (err) {
+    console.error(err);
+}
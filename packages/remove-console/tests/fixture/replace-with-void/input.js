@@ -0,0 +1,4 @@
+const log = (msg) => console.log(msg);
+ready && console.info('ready');
+console.log('statement');
+export default debug ? console.debug : noop;
@@ -0,0 +1,3 @@
+const log = (msg)=>void 0;
+ready && void 0;
+export default debug ? console.debug : noop;
@@ -0,0 +1,3 @@
+import { console } from './logger';
+
+console.log('kept');
@@ -0,0 +1,2 @@
+import { console } from './logger';
+console.log('kept');
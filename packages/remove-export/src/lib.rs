@@ -1,362 +1,3152 @@
 use easy_error::Error;
-use fxhash::FxHashSet;
-use std::mem::take;
-use swc_common::pass::{Repeat, Repeated};
-use swc_common::{SyntaxContext, DUMMY_SP};
+use fxhash::{FxHashMap, FxHashSet};
+use glob::Pattern;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::mem::{replace, take};
+use swc_common::pass::Either;
+use swc_common::comments::{Comment, CommentKind, Comments, NoopComments};
+use swc_common::errors::HANDLER;
+use swc_common::util::take::Take;
+use swc_common::{BytePos, FileName, SourceMap, Span, Spanned, SyntaxContext, DUMMY_SP};
 use swc_core::ecma::{
     ast::*,
-    visit::{Fold, FoldWith, noop_fold_type},
+    atoms::{atom, Atom},
+    parser::{parse_file_as_expr, Syntax},
+    utils::{drop_span, find_pat_ids},
+    visit::{
+        as_folder, noop_visit_mut_type, noop_visit_type, Fold, Visit, VisitMut,
+        VisitMutWith, VisitWith,
+    },
 };
+use swc_core::{common::collections::AHashSet, ecma::utils::collect_decls};
+#[cfg(feature = "plugin")]
+use swc_common::plugin::metadata::TransformPluginMetadataContextKind;
+#[cfg(feature = "plugin")]
+use swc_core::ecma::visit::FoldWith;
+#[cfg(feature = "plugin")]
 use swc_plugin_proxy::TransformPluginProgramMetadata;
+#[cfg(feature = "plugin")]
 use swc_plugin_macro::plugin_transform;
 
+/// Options of remove-export.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Names of the exports to remove. `default` means the default export.
+    ///
+    /// Names with `*`, `?` or `[` are glob patterns, like `get*Props`.
+    ///
+    /// `default:Name` only matches the default export if it is the function
+    /// or class `Name`, or `export default Name`.
+    #[serde(default)]
+    pub exports: Vec<String>,
+
+    /// Additional exports to remove only when building for a given
+    /// environment, applied by [Config::for_env].
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+
+    /// Names of the exports to remove by file, like a report of the exports
+    /// nothing imports produced by a bundler, applied by [Config::for_file].
+    /// Paths match the end of the filename, like `src/utils.ts`.
+    #[serde(default)]
+    pub unused_exports: BTreeMap<String, Vec<String>>,
+
+    /// JSDoc tags marking the exports to remove whatever their name, like
+    /// `removeOnClient` for `/** @removeOnClient */`. Requires comments.
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    /// Functions whose calls initialize the exports to remove whatever their
+    /// name, like `createServerFn` for `export const x = createServerFn(...)`.
+    #[serde(default)]
+    pub factories: Vec<String>,
+
+    /// Patterns of the names of the exports to remove, in addition to
+    /// `exports`.
+    #[serde(default)]
+    pub patterns: Vec<String>,
+
+    /// Syntax of `patterns`.
+    #[serde(default)]
+    pub kind: PatternKind,
+
+    /// Whether the matched exports are removed, or the only ones kept.
+    #[serde(default)]
+    pub mode: Mode,
+
+    /// What a removed default export is replaced with.
+    #[serde(default)]
+    pub default_replacement: DefaultReplacement,
+
+    /// Annotate the empty function replacing a removed default export with
+    /// `/*#__PURE__*/`, for minifiers.
+    #[serde(default)]
+    pub pure_annotations: bool,
+
+    /// Names of the properties to remove from a default-exported object
+    /// literal, like `getStaticProps` of
+    /// `export default { getStaticProps, render }`.
+    #[serde(default)]
+    pub default_properties: Vec<String>,
+
+    /// Glob patterns of the sources of the re-exports to remove, like
+    /// `./data/*`. Empty means re-exports from any source.
+    #[serde(default)]
+    pub from: Vec<String>,
+
+    /// Keep the declarations of matched exports, only removing their `export`
+    /// keyword or specifiers.
+    #[serde(default)]
+    pub unexport: bool,
+
+    /// Also remove CommonJS exports, like `exports.foo = ...`,
+    /// `module.exports.foo = ...` and the properties of
+    /// `module.exports = { ... }`.
+    #[serde(default)]
+    pub commonjs: bool,
+
+    /// What to do with `export * from './foo'`, whose names are unknown.
+    #[serde(default)]
+    pub export_all: ExportAll,
+
+    /// Glob patterns of side-effect imports, like `./server-polyfill`, which
+    /// are dropped along with the removed exports. Other side-effect imports
+    /// are always kept.
+    #[serde(default)]
+    pub side_effect_imports: Vec<String>,
+
+    /// Glob patterns of the files to transform. Empty means all files.
+    #[serde(default)]
+    pub include: Vec<String>,
+
+    /// Glob patterns of the files not to transform, even if included, like
+    /// `**/node_modules/**`.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+
+    /// Glob pattern of the files the config applies to, like
+    /// `pages/**/*.tsx`, matching the end of their path. Used to select the
+    /// config of a file among the list given to the plugin.
+    #[serde(default)]
+    pub test: Option<String>,
+
+    /// Warn about `export { x }` specifiers without any binding for `x`.
+    #[serde(default)]
+    pub warn_phantom_exports: bool,
+
+    /// Warn about the `exports` of the config which match no ES export of the
+    /// module, like a misspelled `getServerSidePorps`. Modules with
+    /// `export * from` are skipped, as their exports are unknown.
+    #[serde(default)]
+    pub warn_unmatched_exports: bool,
+
+    /// Remove top-level expression statements using a removed export, like
+    /// `registry.set('data', getData)`.
+    ///
+    /// Otherwise such exports are kept.
+    #[serde(default)]
+    pub remove_orphaned_statements: bool,
+
+    /// Deprecated and ignored, as unused code is now removed in a single pass.
+    /// Only accepted so existing configs keep working, with a warning.
+    #[serde(default)]
+    pub max_passes: Option<usize>,
+
+    /// Remove the top-level HMR registrations using removed exports, like
+    /// `module.hot.accept('./page', () => rerender(getData))` or
+    /// `if (import.meta.hot) { ... }`, unless they also use other declarations
+    /// of the module than imports.
+    #[serde(default)]
+    pub prune_hmr: bool,
+
+    /// Glob patterns of the globals whose top-level assignments of removed
+    /// exports are removed with them, like `globalThis.__DATA_FN__` for
+    /// `globalThis.__DATA_FN__ = getData` or `window.*`.
+    #[serde(default)]
+    pub global_assignments: Vec<String>,
+
+    /// Export syntaxes to remove matched exports from. Empty means all of
+    /// them.
+    #[serde(default)]
+    pub forms: Vec<ExportForm>,
+
+    /// Replace removed export declarations with `export const foo = undefined`,
+    /// so importers of `foo` still find the binding.
+    #[serde(default)]
+    pub keep_as_undefined: bool,
+
+    /// Remove nothing, but annotate the nodes which would be removed with a
+    /// `/* swc-remove-export: removed */` comment, or log them if comments are
+    /// not available.
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Move the comments of removed nodes to the following nodes, instead of
+    /// dropping them.
+    #[serde(default)]
+    pub keep_comments: bool,
+
+    /// Leave a `/* removed by remove-export: getData */` comment where
+    /// top-level declarations and exports were removed. Requires comments.
+    #[serde(default)]
+    pub annotate_removals: bool,
+
+    /// Fail with an error and leave the module untouched if the kept code
+    /// still references a removed declaration, instead of emitting code which
+    /// throws a `ReferenceError`.
+    #[serde(default)]
+    pub strict: bool,
+
+    /// Emit a note through the diagnostics handler of swc for each removed
+    /// export, import and declaration.
+    #[serde(default)]
+    pub diagnostics: bool,
+
+    /// Append a `/* swc-remove-export-manifest {...} */` comment listing the
+    /// removed exports, imports and declarations as JSON, or log it if
+    /// comments are not available.
+    #[serde(default)]
+    pub manifest: bool,
+
+    /// Log the numbers of removed exports, imports and declarations of each
+    /// module as `tracing` fields, and note them through the diagnostics
+    /// handler of swc.
+    #[serde(default)]
+    pub stats: bool,
+
+    /// Expression the exports replaced by `keepAsUndefined` are initialized
+    /// with, like `() => ({ props: {} })`. Defaults to `undefined`.
+    #[serde(default)]
+    pub stub: Option<String>,
+
+    /// Expression replacing removed export declarations, like
+    /// `() => { throw new Error("server only") }`, so that using them fails
+    /// loudly instead of finding nothing. Implies `keepAsUndefined`, and
+    /// takes precedence over `stub`.
+    #[serde(default)]
+    pub replace_with: Option<String>,
+
+    /// Append `export {};` to the modules whose imports and exports are all
+    /// removed, so they are still parsed as ES modules rather than scripts.
+    /// Defaults to `true`.
+    #[serde(default)]
+    pub preserve_esm: Option<bool>,
+
+    /// Whether the initializers of removed exports, like the call of
+    /// `export const data = fetchAtBuildTime()`, are removed with them.
+    /// Otherwise those with side effects are kept as expression statements.
+    /// Defaults to `true`.
+    #[serde(default)]
+    pub assume_pure_initializers: Option<bool>,
+}
+
+/// What to do with the exports matched by [Config].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Mode {
+    /// Remove the matched exports.
+    #[default]
+    Remove,
+    /// Keep the matched exports, and remove all the others.
+    Keep,
+}
+
+/// Replacement of a removed default export.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum DefaultReplacement {
+    /// `export default function() {}`
+    #[default]
+    EmptyFunction,
+    /// `export default null`
+    Null,
+    /// `export default undefined`
+    Undefined,
+    /// No default export at all.
+    None,
+    /// `export default <expression>`, like `{}` for consumers destructuring the
+    /// default export.
+    Expr(String),
+}
+
+impl From<String> for DefaultReplacement {
+    fn from(s: String) -> Self {
+        match &*s {
+            "emptyFunction" => DefaultReplacement::EmptyFunction,
+            "null" => DefaultReplacement::Null,
+            "undefined" => DefaultReplacement::Undefined,
+            "none" => DefaultReplacement::None,
+            _ => DefaultReplacement::Expr(s),
+        }
+    }
+}
+
+/// Handling of `export * from './foo'`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExportAll {
+    /// Keep them.
+    #[default]
+    Keep,
+    /// Remove them, like matched exports.
+    Remove,
+}
+
+/// Syntax of [Config::patterns].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PatternKind {
+    /// `get*Props`
+    #[default]
+    Glob,
+    /// `^internal_`
+    Regex,
+}
+
+/// Syntax of an export, used to limit the removal with [Config::forms].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExportForm {
+    /// `export function foo() {}`, `export const foo = 1`
+    Decl,
+    /// `export { foo }`, `export { foo } from './foo'`
+    Named,
+    /// `export default foo`
+    Default,
+    /// All of the above.
+    All,
+}
+
+/// Exports to remove only when building for [Rule::env], like
+/// `{ "exports": ["serverOnlyHelpers"], "env": "browser" }`.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rule {
+    /// Names of the exports to remove, like [Config::exports].
+    #[serde(default)]
+    pub exports: Vec<String>,
+
+    /// Environment the rule applies to, compared to the `env` of swc, like
+    /// `production` or `browser`. Rules without `env` always apply.
+    #[serde(default)]
+    pub env: Option<String>,
+}
+
+/// Config accepted by the plugin, either the list of exports to remove or a
+/// [Config] object.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum RemoveExportConfig {
+    Exports(Vec<String>),
+    Config(Box<Config>),
+}
+
+impl Config {
+    /// Returns the config removing `exports`, which the other options can be
+    /// chained to, like `Config::new(["getData"]).mode(Mode::Keep)`.
+    pub fn new<S: Into<String>>(exports: impl IntoIterator<Item = S>) -> Self {
+        Config {
+            exports: exports.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets [Config::mode].
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Adds `patterns` of the syntax `kind` to [Config::patterns].
+    pub fn patterns<S: Into<String>>(mut self, kind: PatternKind, patterns: impl IntoIterator<Item = S>) -> Self {
+        self.kind = kind;
+        self.patterns.extend(patterns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets [Config::default_replacement].
+    pub fn default_replacement(mut self, replacement: DefaultReplacement) -> Self {
+        self.default_replacement = replacement;
+        self
+    }
+
+    /// Sets [Config::forms].
+    pub fn forms(mut self, forms: impl IntoIterator<Item = ExportForm>) -> Self {
+        self.forms = forms.into_iter().collect();
+        self
+    }
+
+    /// Adds the glob `patterns` to [Config::include].
+    pub fn include<S: Into<String>>(mut self, patterns: impl IntoIterator<Item = S>) -> Self {
+        self.include.extend(patterns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Adds the glob `patterns` to [Config::exclude].
+    pub fn exclude<S: Into<String>>(mut self, patterns: impl IntoIterator<Item = S>) -> Self {
+        self.exclude.extend(patterns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Adds a [Rule] removing `exports` when building for `env`.
+    pub fn rule<S: Into<String>>(mut self, env: Option<&str>, exports: impl IntoIterator<Item = S>) -> Self {
+        self.rules.push(Rule {
+            exports: exports.into_iter().map(Into::into).collect(),
+            env: env.map(String::from),
+        });
+        self
+    }
+
+    /// Whether the file named `filename` should be transformed, according to
+    /// [Config::test], [Config::include] and [Config::exclude].
+    pub fn includes(&self, filename: &str) -> bool {
+        self.test.as_ref().map_or(true, |test| matches_path_end(test, filename))
+            && matches_any(&self.include, filename)
+            && !self.exclude.iter().any(|p| Pattern::new(p).is_ok_and(|p| p.matches(filename)))
+    }
+
+    /// Adds the [Config::unused_exports] of the file named `filename` to
+    /// [Config::exports].
+    pub fn for_file(mut self, filename: &str) -> Self {
+        for (path, exports) in take(&mut self.unused_exports) {
+            if filename == path || filename.strip_suffix(&*path).is_some_and(|dir| dir.ends_with('/')) {
+                self.exports.extend(exports);
+            }
+        }
+
+        self
+    }
+
+    /// Whether re-exports from `src` can be removed, according to
+    /// [Config::from].
+    pub fn removes_from(&self, src: &str) -> bool {
+        matches_any(&self.from, src)
+    }
+
+    /// Whether the side-effect import of `src` can be dropped, according to
+    /// [Config::side_effect_imports].
+    pub fn drops_side_effect_import(&self, src: &str) -> bool {
+        !self.side_effect_imports.is_empty() && matches_any(&self.side_effect_imports, src)
+    }
+
+    /// Adds the exports of the [Config::rules] applying to `env` to
+    /// [Config::exports].
+    pub fn for_env(mut self, env: Option<&str>) -> Self {
+        for rule in take(&mut self.rules) {
+            if rule.env.is_none() || rule.env.as_deref() == env {
+                self.exports.extend(rule.exports);
+            }
+        }
+
+        self
+    }
+}
+
+/// Whether `value` matches one of the glob `patterns`, or `patterns` is empty.
+fn matches_any(patterns: &[String], value: &str) -> bool {
+    patterns.is_empty()
+        || patterns
+            .iter()
+            .any(|p| Pattern::new(p).is_ok_and(|p| p.matches(value)))
+}
+
+/// Whether the glob `pattern` matches `path` or one of its trailing parts, like
+/// `pages/*.js` for `/app/src/pages/index.js`.
+fn matches_path_end(pattern: &str, path: &str) -> bool {
+    let Ok(pattern) = Pattern::new(pattern) else {
+        return false;
+    };
+
+    pattern.matches(path)
+        || path
+            .match_indices('/')
+            .any(|(i, _)| pattern.matches(&path[i + 1..]))
+}
+
+/// Parses the JSON config of the plugin, which may also be a list of configs
+/// selected by their [Config::test], like
+/// `[{ "test": "pages/**", "exports": ["getData"] }, { "test": "app/**", ... }]`.
+pub fn parse_configs(json: &str) -> Result<Vec<Config>, String> {
+    match serde_json::from_str(json) {
+        Ok(serde_json::Value::Array(items)) if items.iter().any(|i| i.is_object()) => {
+            serde_json::from_value(serde_json::Value::Array(items))
+                .map_err(|err| format!("remove-export: invalid config `{}`: {}", json, err))
+        }
+        _ => parse_config(json).map(|config| vec![config]),
+    }
+}
+
+/// Returns the first of `configs` which applies to the file named `filename`,
+/// or without [Config::test] if the filename is unknown.
+pub fn select_config(configs: Vec<Config>, filename: Option<&str>) -> Option<Config> {
+    configs.into_iter().find(|config| match filename {
+        Some(filename) => config.includes(filename),
+        None => config.test.is_none(),
+    })
+}
+
+/// Parses the JSON config of the plugin, describing what is wrong with it if
+/// it is invalid.
+pub fn parse_config(json: &str) -> Result<Config, String> {
+    let describe = |err: serde_json::Error| format!("remove-export: invalid config `{}`: {}", json, err);
+
+    // Parsed by variant, as the errors of untagged enums don't tell what is wrong.
+    let config = match serde_json::from_str(json).map_err(describe)? {
+        value @ serde_json::Value::Array(..) => {
+            RemoveExportConfig::Exports(serde_json::from_value(value).map_err(describe)?)
+        }
+        value => RemoveExportConfig::Config(serde_json::from_value(value).map_err(describe)?),
+    };
+
+    Ok(config.into())
+}
+
+impl From<RemoveExportConfig> for Config {
+    fn from(config: RemoveExportConfig) -> Self {
+        match config {
+            RemoveExportConfig::Exports(exports) => Config {
+                exports,
+                ..Default::default()
+            },
+            RemoveExportConfig::Config(config) => *config,
+        }
+    }
+}
+
 /// Note: This paths requires running `resolver` **before** running this.
 pub fn remove_export_exprs(remove_exports: Vec<String>) -> impl Fold {
-    Repeat::new(RemoveExportsExprs {
+    remove_export_exprs_with_config(Config {
+        exports: remove_exports,
+        ..Default::default()
+    })
+}
+
+/// Same as [remove_export_exprs], but takes the whole [Config].
+pub fn remove_export_exprs_with_config(config: Config) -> impl Fold {
+    remove_export_exprs_with_comments(config, None::<NoopComments>)
+}
+
+/// Same as [remove_export_exprs_with_config], but also drops the comments of
+/// removed nodes from `comments`.
+///
+/// Top-level declarations led by `// swc-remove-export-ignore` or `/* @keep */`
+/// are never removed.
+pub fn remove_export_exprs_with_comments<C>(config: Config, comments: Option<C>) -> impl Fold
+where
+    C: Comments + Clone,
+{
+    let (dry_run, diagnostics, manifest, stats) = (config.dry_run, config.diagnostics, config.manifest, config.stats);
+    let pass = RemoveExportsExprs {
+        state: State {
+            names: export_names(&config),
+            default_locals: default_locals(&config),
+            patterns: export_patterns(&config),
+            stub: stub(&config),
+            default_expr: default_expr(&config),
+            config,
+            ..Default::default()
+        },
+        in_lhs_of_var: false,
+        comments: comments.clone(),
+    };
+
+    as_folder(if dry_run || diagnostics || manifest || stats {
+        Either::Left(Report {
+            pass,
+            comments,
+            dry_run,
+            diagnostics,
+            manifest,
+            stats,
+        })
+    } else {
+        Either::Right(pass)
+    })
+}
+
+/// Same as [remove_export_exprs], but removes the exports for which
+/// `predicate` returns `true` instead of matching names.
+pub fn remove_export_exprs_by<F>(predicate: F) -> impl Fold
+where
+    F: Fn(&ExportMatch) -> bool + Send + Sync + 'static,
+{
+    as_folder(RemoveExportsExprs {
         state: State {
-            remove_exports,
+            predicate: Some(ExportPredicate(Box::new(predicate))),
             ..Default::default()
         },
         in_lhs_of_var: false,
+        comments: None::<NoopComments>,
     })
 }
 
+/// What an export is declared as.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExportKind {
+    Fn,
+    Var,
+    Class,
+    /// A type alias or an interface.
+    Type,
+    /// The default export.
+    Default,
+    /// Anything else, e.g. re-exported imports.
+    #[default]
+    Other,
+}
+
+/// An export checked against the predicate of [remove_export_exprs_by].
+#[derive(Debug)]
+pub struct ExportMatch<'a> {
+    /// Name the export is visible as, `default` for the default export.
+    pub name: &'a str,
+    pub kind: ExportKind,
+    /// Whether the export is an async function.
+    pub is_async: bool,
+    /// Whether the export is a generator function.
+    pub is_generator: bool,
+}
+
+struct ExportPredicate(Box<dyn Fn(&ExportMatch) -> bool + Send + Sync>);
+
+impl fmt::Debug for ExportPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ExportPredicate")
+    }
+}
+
+/// Declaration behind a top-level binding or the default export.
+#[derive(Debug, Default, Clone, Copy)]
+struct DeclInfo {
+    kind: ExportKind,
+    is_async: bool,
+    is_generator: bool,
+}
+
+impl DeclInfo {
+    fn of_function(kind: ExportKind, f: &Function) -> Self {
+        DeclInfo {
+            kind,
+            is_async: f.is_async,
+            is_generator: f.is_generator,
+        }
+    }
+
+    fn of_expr(kind: ExportKind, e: &Expr) -> Self {
+        match e {
+            Expr::Fn(f) => DeclInfo::of_function(kind, &f.function),
+            Expr::Arrow(a) => DeclInfo {
+                kind,
+                is_async: a.is_async,
+                is_generator: a.is_generator,
+            },
+            Expr::Paren(p) => DeclInfo::of_expr(kind, &p.expr),
+            _ => DeclInfo {
+                kind,
+                ..Default::default()
+            },
+        }
+    }
+}
+
 /// State of the transforms. Shared by the analyzer and the transform.
 #[derive(Debug, Default)]
 struct State {
     /// Identifiers referenced by non-data function codes.
     ///
-    /// Cleared before running each pass, because we drop ast nodes between the
-    /// passes.
+    /// Once the module is analyzed, also contains everything referenced by the
+    /// top-level declarations they reach through [State::deps].
     refs_from_other: FxHashSet<Id>,
 
-    /// Identifiers referenced by data functions or derivatives.
-    ///
-    /// Preserved between runs, because we should remember derivatives of data
-    /// functions as the data function itself is already removed.
-    refs_from_data_fn: FxHashSet<Id>,
+    /// Identifiers referenced by data functions or derivatives.
+    ///
+    /// Once the module is analyzed, also contains everything referenced by the
+    /// top-level declarations they reach through [State::deps].
+    refs_from_data_fn: FxHashSet<Id>,
+
+    /// Identifiers referenced by each top-level declaration, by the bindings
+    /// it declares.
+    ///
+    /// What a declaration references is only kept if the declaration is.
+    deps: FxHashMap<Id, FxHashSet<Id>>,
+
+    /// Bindings whose declarations have been dropped, to check the output for
+    /// dangling references.
+    removed: FxHashSet<Id>,
+
+    /// Top-level bindings of the module.
+    bindings: FxHashMap<Id, DeclInfo>,
+
+    /// Bindings of the imports of the module.
+    imported: FxHashSet<Id>,
+
+    /// Declaration of the default export.
+    default_decl: DeclInfo,
+
+    /// Name of the function, class or binding exported as `default`.
+    default_local: Option<Atom>,
+
+    /// Top-level type declarations which don't share their name with a value.
+    type_only: FxHashSet<Id>,
+
+    /// Identifiers referenced by types outside of imports and top-level
+    /// declarations, which are not seen by the analyzer.
+    type_refs: FxHashSet<Id>,
+
+    /// Matched exports which are kept because removing them would leave
+    /// kept code dangling.
+    kept_exports: FxHashSet<Id>,
+
+    /// Bindings of top-level declarations marked with an ignore comment, which
+    /// are never removed.
+    ignored: FxHashSet<Id>,
+
+    /// Exports removed along with their declarations.
+    removed_decls: FxHashSet<Id>,
+
+    /// Comments of removed nodes kept by [Config::keep_comments], by the
+    /// position of the nodes.
+    removed_comments: Vec<(BytePos, Vec<Comment>)>,
+
+    config: Config,
+
+    /// Exact names among the `exports` of the config.
+    names: FxHashSet<Atom>,
+
+    /// Names of the default exports to remove, from the `default:Name`
+    /// entries of the `exports` of the config.
+    default_locals: FxHashSet<Atom>,
+
+    /// Names of the exports kept by a `/* swc-remove-export: keep ... */`
+    /// comment of the module, whatever the config says.
+    kept_names: FxHashSet<Atom>,
+
+    /// Names of the exports led by one of the [Config::tags] or initialized by
+    /// one of the [Config::factories].
+    marked: FxHashSet<Atom>,
+
+    /// Glob patterns among the `exports` of the config, and its `patterns`.
+    patterns: Vec<NamePattern>,
+
+    /// Replaces the `exports` of the config if set.
+    predicate: Option<ExportPredicate>,
+
+    /// Initializer of the replaced export declarations, parsed from the
+    /// `replaceWith` or `stub` of the config.
+    stub: Option<Box<Expr>>,
+
+    /// Replacement of the removed default export, parsed from a custom
+    /// `defaultReplacement` of the config.
+    default_expr: Option<Box<Expr>>,
+}
+
+impl State {
+    fn matches(&self, name: &Atom, info: DeclInfo) -> bool {
+        self.matches_local(name, None, info)
+    }
+
+    /// Same as [State::matches], `local` being the binding exported as `name`,
+    /// which the `default:Name` entries of the config are matched against.
+    fn matches_local(&self, name: &Atom, local: Option<&Atom>, info: DeclInfo) -> bool {
+        if self.kept_names.contains(name) {
+            return false;
+        }
+
+        let matched = match &self.predicate {
+            Some(predicate) => predicate.0(&ExportMatch {
+                name,
+                kind: info.kind,
+                is_async: info.is_async,
+                is_generator: info.is_generator,
+            }),
+            None => {
+                self.names.contains(name)
+                    || self.marked.contains(name)
+                    || self.patterns.iter().any(|p| p.matches(name))
+                    || (*name == "default" && local.is_some_and(|l| self.default_locals.contains(l)))
+            }
+        };
+
+        matched != (self.config.mode == Mode::Keep)
+    }
+
+    fn binding(&self, local: &Ident) -> DeclInfo {
+        self.bindings
+            .get(&local.to_id())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn should_remove_identifier(&mut self, i: &Ident) -> Result<bool, Error> {
+        Ok(self.is_removable(&i.to_id()) && self.matches(&i.sym, self.binding(i)))
+    }
+
+    /// `local` is the binding exported as `name`.
+    fn should_remove_specifier(&self, name: &Atom, local: &Ident) -> bool {
+        self.is_removable(&local.to_id()) && self.matches_local(name, Some(&local.sym), self.binding(local))
+    }
+
+    fn is_removable(&self, id: &Id) -> bool {
+        !self.kept_exports.contains(id) && !self.ignored.contains(id)
+    }
+
+    /// Whether `item` declares an export which is going to be removed.
+    fn is_removed_export_decl(&mut self, item: &ModuleItem) -> bool {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(..)) if !self.acts_on(ExportForm::Decl) => {
+                false
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(e)) => match &e.decl {
+                Decl::Fn(f) => self.should_remove_identifier(&f.ident).unwrap_or(false),
+                Decl::Var(v) => {
+                    !v.decls.is_empty() && v.decls.iter().all(|d| self.should_remove_declarator(d))
+                }
+                Decl::TsModule(m) => namespace_id(m).is_some_and(|id| self.should_remove_export_binding(id)),
+                Decl::Class(c) => self.should_remove_export_binding(&c.ident),
+                Decl::TsEnum(e) => self.should_remove_export_binding(&e.id),
+                _ => false,
+            },
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(..))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(..)) => {
+                self.should_remove_default()
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether `m` surely has none of the exports matched by the config, in
+    /// which case it is left untouched without being analyzed.
+    fn matches_nothing(&self, m: &Module) -> bool {
+        // Predicates and the other options may match more than names.
+        if self.config.mode == Mode::Keep
+            || self.predicate.is_some()
+            || self.config.commonjs
+            || self.config.export_all == ExportAll::Remove
+            || self.config.manifest
+            || !self.config.default_properties.is_empty()
+        {
+            return false;
+        }
+
+        !m.body.iter().any(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(e)) => {
+                let ids = match &e.decl {
+                    Decl::TsInterface(i) => vec![i.id.clone()],
+                    Decl::TsTypeAlias(a) => vec![a.id.clone()],
+                    Decl::TsEnum(e) => vec![e.id.clone()],
+                    Decl::TsModule(m) => namespace_id(m).cloned().into_iter().collect(),
+                    Decl::Var(v) => find_pat_ids(&v.decls),
+                    Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }) => {
+                        vec![ident.clone()]
+                    }
+                    Decl::Using(..) => vec![],
+                };
+                ids.iter().any(|id| self.matches(&id.sym, DeclInfo::default()))
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(e)) => e.specifiers.iter().any(|s| {
+                let name = match s {
+                    ExportSpecifier::Named(ExportNamedSpecifier { orig, exported, .. }) => {
+                        exported.as_ref().unwrap_or(orig).atom()
+                    }
+                    ExportSpecifier::Namespace(ExportNamespaceSpecifier { name, .. }) => name.atom(),
+                    ExportSpecifier::Default(ExportDefaultSpecifier { exported }) => &exported.sym,
+                };
+                let local = match s {
+                    ExportSpecifier::Named(ExportNamedSpecifier { orig, .. }) => Some(orig.atom()),
+                    _ => None,
+                };
+                self.matches_local(name, local, DeclInfo::default())
+            }),
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(..) | ModuleDecl::ExportDefaultExpr(..)) => {
+                self.matches_local(&atom!("default"), self.default_local.as_ref(), DeclInfo::default())
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(..)) => true,
+            _ => false,
+        })
+    }
+
+    /// Finds matched exports which have to be kept, because they are
+    ///
+    ///  - live bindings assigned to by code which is kept.
+    ///  - used by top-level statements, unless such statements are removed.
+    fn find_kept_exports(&mut self, m: &Module) {
+        // The declarations are kept anyway.
+        if self.config.unexport {
+            return;
+        }
+
+        for item in &m.body {
+            if let ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(e)) = item {
+                match &e.decl {
+                    Decl::Fn(f) if self.is_removed_export_decl(item) => {
+                        self.removed_decls.insert(f.ident.to_id());
+                    }
+                    Decl::TsModule(m) if self.is_removed_export_decl(item) => {
+                        self.removed_decls.extend(namespace_id(m).map(|id| id.to_id()));
+                    }
+                    Decl::Class(c) if self.is_removed_export_decl(item) => {
+                        self.removed_decls.insert(c.ident.to_id());
+                    }
+                    Decl::TsEnum(e) if self.is_removed_export_decl(item) => {
+                        self.removed_decls.insert(e.id.to_id());
+                    }
+                    Decl::Var(v) => {
+                        for d in &v.decls {
+                            if self.should_remove_declarator(d) {
+                                self.removed_decls.extend(find_pat_ids::<_, Id>(&d.name));
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Reassignments of removed exports, like `getData = wrap(getData)`, are
+        // removed with them.
+        let mut assigned = AssignedIds::default();
+        for item in &m.body {
+            if !self.is_removed_export_decl(item) && !self.is_removed_reassignment(item) {
+                item.visit_with(&mut assigned);
+            }
+        }
+
+        let mut exported = vec![];
+        for item in &m.body {
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(e)) if self.acts_on(ExportForm::Decl) => match &e.decl {
+                    Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }) => {
+                        exported.push((ident.sym.clone(), ident.clone()))
+                    }
+                    Decl::Var(v) => exported.extend(
+                        find_pat_ids::<_, Ident>(&v.decls)
+                            .into_iter()
+                            .map(|i| (i.sym.clone(), i)),
+                    ),
+                    _ => {}
+                },
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                    specifiers,
+                    src: None,
+                    ..
+                })) if self.acts_on(ExportForm::Named) => {
+                    for s in specifiers {
+                        if let ExportSpecifier::Named(ExportNamedSpecifier {
+                            orig: ModuleExportName::Ident(orig),
+                            exported: name,
+                            ..
+                        }) = s
+                        {
+                            let name = match name {
+                                Some(ModuleExportName::Ident(name)) => name.sym.clone(),
+                                Some(ModuleExportName::Str(name)) => name.value.clone(),
+                                None => orig.sym.clone(),
+                            };
+                            exported.push((name, orig.clone()));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for (name, local) in exported {
+            if assigned.ids.contains(&local.to_id()) && self.should_remove_specifier(&name, &local) {
+                tracing::warn!(
+                    "remove_export_exprs: not removing `{}` because kept code assigns to `{}`",
+                    name,
+                    local.sym
+                );
+                self.kept_exports.insert(local.to_id());
+            }
+        }
+
+        if self.config.remove_orphaned_statements {
+            return;
+        }
+
+        for item in &m.body {
+            if self.is_removed_reassignment(item)
+                || matches!(item, ModuleItem::Stmt(s) if self.is_pruned_hmr_stmt(s) || self.is_removed_global_assignment(s))
+            {
+                continue;
+            }
+
+            if let ModuleItem::Stmt(s @ Stmt::Expr(..)) = item {
+                let mut used = IdentCollector::default();
+                s.visit_with(&mut used);
+
+                for id in used.ids.intersection(&self.removed_decls) {
+                    tracing::warn!(
+                        "remove_export_exprs: not removing `{}` because a top-level statement \
+                         uses it, enable `removeOrphanedStatements` to remove the statement",
+                        id.0
+                    );
+                    self.kept_exports.insert(id.clone());
+                }
+            }
+        }
+        self.removed_decls.retain(|id| !self.kept_exports.contains(id));
+    }
+
+    /// Moves the matched bindings of the exported variable declarations of `m`
+    /// to plain declarations for [Config::unexport], like `export const a = 1,
+    /// b = 2` to `const a = 1; export const b = 2`.
+    ///
+    /// Patterns binding both matched and other exports, like `{ a, b }`, are
+    /// declared without `export`, and the other exports re-exported with
+    /// `export { b }`.
+    fn split_unexported_decls(&self, m: &mut Module) {
+        for item in take(&mut m.body) {
+            let ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                span,
+                decl: Decl::Var(mut v),
+            })) = item
+            else {
+                m.body.push(item);
+                continue;
+            };
+            let is_matched = |d: &VarDeclarator| {
+                find_pat_ids::<_, Ident>(&d.name)
+                    .iter()
+                    .any(|id| self.should_remove_export_binding(id))
+            };
+            if !v.decls.iter().any(is_matched) {
+                m.body.push(ModuleDecl::ExportDecl(ExportDecl { span, decl: Decl::Var(v) }).into());
+                continue;
+            }
+
+            let mut reexported = vec![];
+            // Consecutive declarators stay together, keeping the order of their
+            // initializers.
+            let mut runs: Vec<(bool, Vec<VarDeclarator>)> = vec![];
+            for d in v.decls.take() {
+                let matched = is_matched(&d);
+                if matched {
+                    reexported.extend(
+                        find_pat_ids::<_, Ident>(&d.name)
+                            .into_iter()
+                            .filter(|id| !self.should_remove_export_binding(id)),
+                    );
+                }
+                match runs.last_mut() {
+                    Some((m, decls)) if *m == matched => decls.push(d),
+                    _ => runs.push((matched, vec![d])),
+                }
+            }
+
+            for (matched, decls) in runs {
+                let decl = Decl::Var(Box::new(VarDecl {
+                    decls,
+                    ..(*v).clone()
+                }));
+                m.body.push(if matched {
+                    ModuleItem::Stmt(Stmt::Decl(decl))
+                } else {
+                    ModuleDecl::ExportDecl(ExportDecl { span, decl }).into()
+                });
+            }
+            if !reexported.is_empty() {
+                m.body.push(
+                    ModuleDecl::ExportNamed(NamedExport {
+                        span: DUMMY_SP,
+                        specifiers: reexported
+                            .into_iter()
+                            .map(|id| {
+                                ExportSpecifier::Named(ExportNamedSpecifier {
+                                    span: DUMMY_SP,
+                                    orig: id.into(),
+                                    exported: None,
+                                    is_type_only: false,
+                                })
+                            })
+                            .collect(),
+                        src: None,
+                        type_only: false,
+                        with: None,
+                    })
+                    .into(),
+                );
+            }
+        }
+    }
+
+    /// Whether `item` is a top-level statement reassigning an export removed
+    /// along with its declaration.
+    fn is_removed_reassignment(&self, item: &ModuleItem) -> bool {
+        match item {
+            ModuleItem::Stmt(s) => reassigned_binding(s).is_some_and(|id| self.removed_decls.contains(&id)),
+            _ => false,
+        }
+    }
+
+    /// Whether the exported declarator `d` is going to be removed, because all
+    /// of its bindings are.
+    fn should_remove_declarator(&self, d: &VarDeclarator) -> bool {
+        let ids = find_pat_ids::<_, Ident>(&d.name);
+        !ids.is_empty() && ids.iter().all(|id| self.should_remove_export_binding(id))
+    }
+
+    /// Whether the initializer of the exported declarator `d` is kept as an
+    /// expression statement if `d` is removed, according to
+    /// [Config::assume_pure_initializers].
+    fn keeps_initializer(&self, d: &VarDeclarator) -> bool {
+        !self.config.assume_pure_initializers.unwrap_or(true) && d.init.as_deref().is_some_and(has_side_effects)
+    }
+
+    /// Whether the binding `id` of an exported declaration is going to be
+    /// removed.
+    fn should_remove_export_binding(&self, id: &Ident) -> bool {
+        self.acts_on(ExportForm::Decl) && self.should_remove_specifier(&id.sym, id)
+    }
+
+    /// Whether the exported type declaration `id` is going to be removed.
+    ///
+    /// Types sharing their name with a value are kept, as they may describe
+    /// the value for the importers.
+    fn should_remove_type(&self, id: &Ident) -> bool {
+        self.type_only.contains(&id.to_id())
+            && self.acts_on(ExportForm::Decl)
+            && self.should_remove_specifier(&id.sym, id)
+    }
+
+    /// Whether removed named exports are replaced by `undefined` or the stub.
+    fn stubs_exports(&self) -> bool {
+        self.config.keep_as_undefined || self.config.replace_with.is_some()
+    }
+
+    /// Returns the name of the export declared by `e`, if the declaration is
+    /// going to be replaced by `undefined`.
+    ///
+    /// Other removed exports are replaced after the transform by
+    /// [add_export_stubs].
+    fn undefined_export(&mut self, e: &ExportDecl) -> Option<Ident> {
+        if !self.stubs_exports() || !self.acts_on(ExportForm::Decl) {
+            return None;
+        }
+
+        let name = match &e.decl {
+            Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }) => ident,
+            Decl::Var(v) => match v.decls.as_slice() {
+                [VarDeclarator {
+                    name: Pat::Ident(id),
+                    ..
+                }] => &id.id,
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        if self.should_remove_specifier(&name.sym, name) {
+            Some(name.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Whether `s` is a top-level statement using exports removed along with
+    /// their declarations.
+    fn is_orphaned_stmt(&self, s: &Stmt) -> bool {
+        if reassigned_binding(s).is_some_and(|id| self.removed_decls.contains(&id))
+            || self.is_pruned_hmr_stmt(s)
+            || self.is_removed_global_assignment(s)
+        {
+            return true;
+        }
+
+        if !self.config.remove_orphaned_statements || !matches!(s, Stmt::Expr(..)) {
+            return false;
+        }
+
+        self.uses_removed_decls(s)
+    }
+
+    /// Whether `n` uses exports removed along with their declarations.
+    fn uses_removed_decls<N: VisitWith<IdentCollector>>(&self, n: &N) -> bool {
+        let mut used = IdentCollector::default();
+        n.visit_with(&mut used);
+        !used.ids.is_disjoint(&self.removed_decls)
+    }
+
+    /// Whether `s` is a HMR registration removed along with the exports it
+    /// uses, according to [Config::prune_hmr].
+    fn is_pruned_hmr_stmt(&self, s: &Stmt) -> bool {
+        if !self.config.prune_hmr || !is_hmr_stmt(s) {
+            return false;
+        }
+
+        let mut used = IdentCollector::default();
+        s.visit_with(&mut used);
+        !used.ids.is_disjoint(&self.removed_decls)
+            && used.ids.iter().all(|id| {
+                !self.bindings.contains_key(id) || self.removed_decls.contains(id) || self.imported.contains(id)
+            })
+    }
+
+    /// Whether `s` assigns removed exports to one of the
+    /// [Config::global_assignments], and is removed with them.
+    fn is_removed_global_assignment(&self, s: &Stmt) -> bool {
+        let Stmt::Expr(ExprStmt { expr, .. }) = s else {
+            return false;
+        };
+        let Expr::Assign(AssignExpr {
+            op: op!("="),
+            left: AssignTarget::Simple(SimpleAssignTarget::Member(left)),
+            right,
+            ..
+        }) = &**expr
+        else {
+            return false;
+        };
+
+        !self.config.global_assignments.is_empty()
+            && member_path(left).is_some_and(|path| {
+                self.config
+                    .global_assignments
+                    .iter()
+                    .any(|p| Pattern::new(p).is_ok_and(|p| p.matches(&path)))
+            })
+            && self.uses_removed_decls(&**right)
+    }
+
+    fn should_remove_default(&mut self) -> bool {
+        self.acts_on(ExportForm::Default) && self.matches_local(&atom!("default"), self.default_local.as_ref(), self.default_decl)
+    }
+
+    /// Whether exports of the `form` syntax can be removed.
+    fn acts_on(&self, form: ExportForm) -> bool {
+        let forms = &self.config.forms;
+        forms.is_empty() || forms.contains(&ExportForm::All) || forms.contains(&form)
+    }
+
+    /// Extends the references from data functions and from other code with
+    /// the references of the top-level declarations they reach.
+    ///
+    /// Declarations which are not referenced by data functions are kept, so
+    /// they keep what they reference too.
+    fn resolve_deps(&mut self) {
+        self.refs_from_data_fn = reachable(&self.deps, take(&mut self.refs_from_data_fn));
+
+        let kept = self
+            .deps
+            .keys()
+            .filter(|id| !self.refs_from_data_fn.contains(*id))
+            .chain(&self.ignored)
+            .chain(&self.type_refs)
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut roots = take(&mut self.refs_from_other);
+        roots.extend(kept);
+        self.refs_from_other = reachable(&self.deps, roots);
+    }
+
+    /// Forgets everything learned about the current module, so that reusing
+    /// the transform for another module starts clean.
+    fn reset_for_new_module(&mut self) {
+        *self = State {
+            config: take(&mut self.config),
+            names: take(&mut self.names),
+            default_locals: take(&mut self.default_locals),
+            patterns: take(&mut self.patterns),
+            predicate: self.predicate.take(),
+            stub: self.stub.take(),
+            default_expr: self.default_expr.take(),
+            ..Default::default()
+        };
+    }
+}
+
+struct Analyzer<'a> {
+    state: &'a mut State,
+    in_lhs_of_var: bool,
+    in_data_fn: bool,
+    /// Bindings of the top-level declaration being visited, whose references
+    /// are recorded in [State::deps].
+    declaring: Vec<Id>,
+    /// Whether the pattern being visited is an exported one whose matched
+    /// bindings are dropped, along with their default values.
+    in_partial_export: bool,
+    comments: &'a dyn Comments,
+}
+
+impl Analyzer<'_> {
+    fn add_ref(&mut self, id: Id) {
+        tracing::trace!("add_ref({}{:?}, data = {})", id.0, id.1, self.in_data_fn);
+        if self.in_data_fn {
+            self.state.refs_from_data_fn.insert(id);
+        } else if self.declaring.is_empty() {
+            self.state.refs_from_other.insert(id);
+        } else {
+            for declaring in &self.declaring {
+                self.state.deps.entry(declaring.clone()).or_default().insert(id.clone());
+            }
+        }
+    }
+
+    fn visit_mut_exported_declarator(&mut self, d: &mut VarDeclarator) {
+        let old_in_data = self.in_data_fn;
+
+        if self.state.should_remove_declarator(d) && self.state.keeps_initializer(d) {
+            let mut init = d.init.take();
+
+            self.in_data_fn = true;
+            d.visit_mut_with(self);
+
+            self.in_data_fn = old_in_data;
+            init.visit_mut_with(self);
+            d.init = init;
+            return;
+        } else if self.state.should_remove_declarator(d) {
+            self.state.refs_from_data_fn.extend(type_refs(&*d));
+            self.in_data_fn = true;
+        } else if !self.in_data_fn {
+            // Matched bindings of a pattern are dropped from it, unless it has a rest
+            // element whose value would change.
+            let partial = !has_object_rest(&d.name);
+
+            for id in find_pat_ids::<_, Ident>(&d.name) {
+                // Kept exports are used by the importers of the module.
+                self.in_data_fn = partial && self.state.should_remove_export_binding(&id);
+                self.add_ref(id.to_id());
+            }
+            self.in_data_fn = false;
+
+            self.in_partial_export = partial;
+            d.visit_mut_with(self);
+            self.in_partial_export = false;
+            return;
+        }
+
+        d.visit_mut_with(self);
+        self.in_data_fn = old_in_data;
+    }
+
+    /// Visits the default `value` of the bindings `ids` of a pattern, which only
+    /// they reference, so it is removed along with them.
+    fn visit_mut_default_value(&mut self, value: &mut Box<Expr>, ids: Vec<Ident>) {
+        if !self.in_lhs_of_var {
+            value.visit_mut_with(self);
+            return;
+        }
+
+        let in_partial_export = take(&mut self.in_partial_export);
+        self.in_lhs_of_var = false;
+        if in_partial_export && !self.in_data_fn {
+            if ids.iter().all(|id| self.state.should_remove_export_binding(id)) {
+                self.mark_as_data(value);
+            } else {
+                value.visit_mut_with(self);
+            }
+        } else if !self.declaring.is_empty() {
+            let declaring = replace(&mut self.declaring, ids.iter().map(Ident::to_id).collect());
+            value.visit_mut_with(self);
+            self.declaring = declaring;
+        } else {
+            value.visit_mut_with(self);
+        }
+        self.in_lhs_of_var = true;
+        self.in_partial_export = in_partial_export;
+    }
+
+    /// Strips the `export` of the matched exports of `d`, keeping their
+    /// declarations.
+    ///
+    /// Returns `d` back as an error if it exports nothing matched. Variable
+    /// declarations are split beforehand by [State::split_unexported_decls].
+    fn unexport(&mut self, d: ModuleDecl) -> Result<ModuleItem, ModuleDecl> {
+        match d {
+            ModuleDecl::ExportDecl(ExportDecl { span, decl }) => {
+                let matched = match &decl {
+                    Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }) => {
+                        self.state.should_remove_export_binding(ident)
+                    }
+                    _ => false,
+                };
+                if !matched {
+                    return Err(ModuleDecl::ExportDecl(ExportDecl { span, decl }));
+                }
+
+                let mut item = ModuleItem::Stmt(Stmt::Decl(decl));
+                item.visit_mut_with(self);
+                Ok(item)
+            }
+            ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { span, decl })
+                if self.state.should_remove_default() =>
+            {
+                let decl = match decl {
+                    DefaultDecl::Fn(FnExpr {
+                        ident: Some(ident),
+                        function,
+                    }) => Decl::Fn(FnDecl {
+                        ident,
+                        declare: false,
+                        function,
+                    }),
+                    DefaultDecl::Class(ClassExpr {
+                        ident: Some(ident),
+                        class,
+                    }) => Decl::Class(ClassDecl {
+                        ident,
+                        declare: false,
+                        class,
+                    }),
+                    // Anonymous, so only kept for the side effects of its class.
+                    DefaultDecl::Fn(f) => return Ok(self.unexport_expr(span, Expr::Fn(f))),
+                    DefaultDecl::Class(c) => return Ok(self.unexport_expr(span, Expr::Class(c))),
+                    DefaultDecl::TsInterfaceDecl(i) => Decl::TsInterface(i),
+                };
+
+                let mut item = ModuleItem::Stmt(Stmt::Decl(decl));
+                item.visit_mut_with(self);
+                Ok(item)
+            }
+            ModuleDecl::ExportDefaultExpr(e) if self.state.should_remove_default() => Ok(self.unexport_expr(e.span, *e.expr)),
+            _ => Err(d),
+        }
+    }
+
+    /// Keeps the anonymous default export `expr` as an expression statement,
+    /// like `sideEffect()` for `export default sideEffect()`.
+    fn unexport_expr(&mut self, span: Span, expr: Expr) -> ModuleItem {
+        // Parenthesized, as statements can't start with `function` or `class`.
+        let expr = match expr {
+            e @ (Expr::Fn(..) | Expr::Class(..) | Expr::Object(..)) => Box::new(Expr::Paren(ParenExpr {
+                span: DUMMY_SP,
+                expr: Box::new(e),
+            })),
+            e => Box::new(e),
+        };
+        let mut item = ModuleItem::Stmt(Stmt::Expr(ExprStmt { span, expr }));
+        item.visit_mut_with(self);
+        item
+    }
+
+    /// Removes matched CommonJS exports assigned by `s`.
+    ///
+    /// Returns whether `s` assigns CommonJS exports, in which case it has been
+    /// visited.
+    fn visit_mut_commonjs_exports(&mut self, s: &mut Stmt) -> bool {
+        let Stmt::Expr(ExprStmt { span, expr }) = s else {
+            return false;
+        };
+        let span = *span;
+
+        // `Object.defineProperty(exports, "foo", { get: ... })`
+        if defined_export_name(expr).is_some_and(|name| self.state.matches(name, DeclInfo::default())) {
+            self.mark_as_data(expr);
+            drop_comments(self.comments, span, self.state);
+            *s = Stmt::Empty(EmptyStmt { span });
+            return true;
+        }
+
+        // `exports.foo = exports.bar = void 0`, declaring the exports in TypeScript
+        // and Babel output.
+        if let Some(targets) = void_export_chain(expr) {
+            let count = targets.len();
+            let kept = targets
+                .into_iter()
+                .filter(|m| !commonjs_export_name(m).is_some_and(|name| self.state.matches(name, DeclInfo::default())))
+                .collect::<Vec<_>>();
+            if kept.len() == count {
+                return false;
+            }
+
+            if kept.is_empty() {
+                drop_comments(self.comments, span, self.state);
+                *s = Stmt::Empty(EmptyStmt { span });
+            } else {
+                let void = Box::new(Expr::Unary(UnaryExpr {
+                    span: DUMMY_SP,
+                    op: op!("void"),
+                    arg: 0.into(),
+                }));
+                **expr = *kept.into_iter().rev().fold(void, |right, left| {
+                    Box::new(Expr::Assign(AssignExpr {
+                        span: DUMMY_SP,
+                        op: op!("="),
+                        left: left.into(),
+                        right,
+                    }))
+                });
+            }
+            return true;
+        }
+        let Expr::Assign(AssignExpr {
+            op: op!("="),
+            left: AssignTarget::Simple(SimpleAssignTarget::Member(left)),
+            right,
+            ..
+        }) = &mut **expr
+        else {
+            return false;
+        };
+
+        // `exports.foo = ...`, `module.exports.foo = ...`
+        if let Some(name) = commonjs_export_name(left) {
+            if !self.state.matches(name, DeclInfo::of_expr(ExportKind::Other, right)) {
+                return false;
+            }
+
+            self.mark_as_data(right);
+            drop_comments(self.comments, span, self.state);
+            *s = Stmt::Empty(EmptyStmt { span });
+            return true;
+        }
+
+        // `module.exports = { ... }`
+        let Expr::Object(obj) = &mut **right else {
+            return false;
+        };
+        if !is_module_exports(left) {
+            return false;
+        }
+
+        self.visit_mut_exported_props(obj, |state, name| state.matches(name, DeclInfo::default()));
+
+        true
+    }
+
+    /// Removes the properties of the exported object `obj` for which `remove`
+    /// returns `true`, visiting the other ones.
+    fn visit_mut_exported_props(&mut self, obj: &mut ObjectLit, remove: fn(&State, &Atom) -> bool) {
+        obj.props = take(&mut obj.props)
+            .into_iter()
+            .filter_map(|mut prop| {
+                let name = match &prop {
+                    PropOrSpread::Prop(p) => match &**p {
+                        Prop::Shorthand(i) => Some(&i.sym),
+                        Prop::KeyValue(KeyValueProp { key, .. })
+                        | Prop::Method(MethodProp { key, .. })
+                        | Prop::Getter(GetterProp { key, .. }) => prop_name(key),
+                        _ => None,
+                    },
+                    PropOrSpread::Spread(..) => None,
+                };
+
+                if name.is_some_and(|name| remove(self.state, name)) {
+                    self.mark_as_data(&mut prop);
+                    None
+                } else {
+                    prop.visit_mut_with(self);
+                    Some(prop)
+                }
+            })
+            .collect();
+    }
+
+    /// Visits the declaration `d`, including the bodies of namespaces which
+    /// are skipped by [noop_visit_mut_type].
+    fn visit_mut_namespace(&mut self, d: &mut Decl) {
+        match d {
+            Decl::TsModule(m) => {
+                if let Some(body) = &mut m.body {
+                    self.visit_mut_namespace_body(body);
+                }
+            }
+            d => d.visit_mut_with(self),
+        }
+    }
+
+    fn visit_mut_namespace_body(&mut self, b: &mut TsNamespaceBody) {
+        match b {
+            TsNamespaceBody::TsModuleBlock(b) => {
+                for item in &mut b.body {
+                    match item {
+                        ModuleItem::Stmt(Stmt::Decl(d)) => self.visit_mut_namespace(d),
+                        ModuleItem::Stmt(s) => s.visit_mut_with(self),
+                        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(e)) => {
+                            self.visit_mut_namespace(&mut e.decl)
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            TsNamespaceBody::TsNamespaceDecl(d) => self.visit_mut_namespace_body(&mut d.body),
+        }
+    }
+
+    /// Visits the top-level declaration `d`, recording what each of its
+    /// bindings references in [State::deps].
+    fn visit_mut_top_level_decl(&mut self, d: &mut Decl) {
+        if let Decl::Var(v) = d {
+            for d in &mut v.decls {
+                self.declaring = find_pat_ids(&d.name);
+                self.add_type_refs(&*d);
+                d.visit_mut_with(self);
+            }
+        } else {
+            self.declaring = decl_ids(d);
+            self.add_type_refs(&*d);
+            self.visit_mut_namespace(d);
+        }
+
+        self.declaring.clear();
+    }
+
+    /// Records the identifiers referenced by the types in `n`, which are
+    /// skipped by [noop_visit_mut_type].
+    fn add_type_refs<N: VisitWith<TypeRefCollector>>(&mut self, n: &N) {
+        let refs = type_refs(n);
+
+        if self.declaring.is_empty() {
+            self.state.type_refs.extend(refs);
+            return;
+        }
+        for declaring in &self.declaring {
+            self.state.deps.entry(declaring.clone()).or_default().extend(refs.iter().cloned());
+        }
+    }
+
+    /// Marks identifiers in `n` as referenced from a data function.
+    fn mark_as_data<N: VisitMutWith<Self>>(&mut self, n: &mut N) {
+        let old_in_data = self.in_data_fn;
+        self.in_data_fn = true;
+        n.visit_mut_with(self);
+        self.in_data_fn = old_in_data;
+    }
+
+    fn check_default<T: VisitMutWith<Self>>(&mut self, e: &mut T) {
+        if self.state.should_remove_default() {
+            let old_in_data = self.in_data_fn;
+
+            self.in_data_fn = true;
+
+            e.visit_mut_children_with(self);
+
+            self.in_data_fn = old_in_data;
+
+            return;
+        }
+
+        e.visit_mut_children_with(self)
+    }
+
+    /// Drops [ExportDecl] if all specifiers are removed.
+    ///
+    /// Takes the item by value, as most of its removals replace it.
+    fn analyze_module_item(&mut self, s: ModuleItem) -> ModuleItem {
+        let mut s = match s {
+            ModuleItem::ModuleDecl(s) if self.state.config.unexport => match self.unexport(s) {
+                Ok(s) => return s,
+                Err(s) => ModuleItem::ModuleDecl(s),
+            },
+            ModuleItem::Stmt(mut s) if self.state.config.commonjs => {
+                if self.visit_mut_commonjs_exports(&mut s) {
+                    return ModuleItem::Stmt(s);
+                }
+                ModuleItem::Stmt(s)
+            }
+            _ => s,
+        };
+
+        if let ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(e)) = &s {
+            if let Some(name) = self.state.undefined_export(e) {
+                let span = e.span;
+
+                let old_in_data = self.in_data_fn;
+                self.in_data_fn = true;
+                s.visit_mut_children_with(self);
+                self.in_data_fn = old_in_data;
+
+                // The replacement is kept by the transform.
+                self.state.kept_exports.insert(name.to_id());
+                self.add_ref(name.to_id());
+
+                let init = self.state.stub.clone().unwrap_or_else(undefined);
+                return export_stub(span, name, init);
+            }
+        }
+
+        match s {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                span,
+                decl: Decl::TsInterface(ref i),
+            })) if self.state.should_remove_type(&i.id) => {
+                drop_comments(self.comments, span, self.state);
+                self.state.refs_from_data_fn.extend(type_refs(&s));
+
+                return ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span }));
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                span,
+                decl: Decl::TsTypeAlias(ref a),
+            })) if self.state.should_remove_type(&a.id) => {
+                drop_comments(self.comments, span, self.state);
+                self.state.refs_from_data_fn.extend(type_refs(&s));
+
+                return ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span }));
+            }
+            // `export namespace Utils { ... }`
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                span,
+                decl: Decl::TsModule(m),
+            })) if namespace_id(&m).is_some() => {
+                let id = namespace_id(&m).unwrap().clone();
+
+                if self.state.should_remove_export_binding(&id) {
+                    drop_comments(self.comments, span, self.state);
+                    self.state.refs_from_data_fn.extend(type_refs(&m));
+
+                    let old_in_data = self.in_data_fn;
+                    self.in_data_fn = true;
+                    self.visit_mut_namespace(&mut Decl::TsModule(m));
+                    self.in_data_fn = old_in_data;
+
+                    return ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span }));
+                }
+
+                // Kept exports are used by the importers of the module.
+                self.add_ref(id.to_id());
+
+                let mut decl = Decl::TsModule(m);
+                self.visit_mut_namespace(&mut decl);
+
+                return ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { span, decl }));
+            }
+            // `export class Store extends Model { ... }`
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                span,
+                decl: Decl::Class(mut c),
+            })) if self.state.should_remove_export_binding(&c.ident) => {
+                self.state.removed.insert(c.ident.to_id());
+                drop_comments(self.comments, span, self.state);
+                self.mark_as_data(&mut c);
+
+                return ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span }));
+            }
+            // `export enum Mode { ... }`, whose member initializers may use imports.
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                span,
+                decl: Decl::TsEnum(mut e),
+            })) if self.state.should_remove_export_binding(&e.id) => {
+                self.state.removed.insert(e.id.to_id());
+                drop_comments(self.comments, span, self.state);
+                self.mark_as_data(&mut e);
+
+                return ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span }));
+            }
+            ModuleItem::Stmt(Stmt::Decl(mut d)) => {
+                self.visit_mut_top_level_decl(&mut d);
+
+                return ModuleItem::Stmt(Stmt::Decl(d));
+            }
+            // `export default { getStaticProps, render }`
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(mut e))
+                if e.expr.is_object()
+                    && !self.state.config.default_properties.is_empty()
+                    && !self.state.should_remove_default() =>
+            {
+                if let Expr::Object(obj) = &mut *e.expr {
+                    self.visit_mut_exported_props(obj, |state, name| {
+                        state.config.default_properties.iter().any(|p| p == &**name)
+                    });
+                }
+
+                return ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(e));
+            }
+            // `export = getData`
+            ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(mut a))
+                if matches!(&*a.expr, Expr::Ident(i) if self.state.should_remove_specifier(&i.sym, i)) =>
+            {
+                drop_comments(self.comments, a.span, self.state);
+                self.mark_as_data(&mut a.expr);
+
+                return ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span: a.span }));
+            }
+            ModuleItem::Stmt(mut s) if self.state.is_orphaned_stmt(&s) => {
+                let span = s.span();
+                drop_comments(self.comments, span, self.state);
+
+                let old_in_data = self.in_data_fn;
+                self.in_data_fn = true;
+                s.visit_mut_children_with(self);
+                self.in_data_fn = old_in_data;
+
+                return ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span }));
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(mut e)) if !e.specifiers.is_empty() => {
+                e.visit_mut_with(self);
+
+                if e.specifiers.is_empty() {
+                    drop_comments(self.comments, e.span, self.state);
+                    return ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span: e.span }));
+                }
+
+                return ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(e));
+            }
+            _ => {}
+        };
+
+        // Visit children to ensure that all references is added to the scope.
+        s.visit_mut_children_with(self);
+
+        if let ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(e)) = &s {
+            match &e.decl {
+                Decl::Fn(..) if !self.state.acts_on(ExportForm::Decl) => {}
+                Decl::Fn(f) => {
+                    if let Ok(should_remove_identifier) = self.state.should_remove_identifier(&f.ident) {
+                        if should_remove_identifier {
+                            // Including the types of its overloads.
+                            self.state.refs_from_data_fn.extend(type_refs(&s));
+                            self.state.removed.insert(f.ident.to_id());
+                            drop_comments(self.comments, e.span, self.state);
+                            return ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span: e.span }));
+                        }
+                    } else {
+                        return s;
+                    }
+                }
+
+                Decl::Var(d) => {
+                    if d.decls.is_empty() {
+                        drop_comments(self.comments, e.span, self.state);
+                        return ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span: e.span }));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        s
+    }
+}
+
+impl VisitMut for Analyzer<'_> {
+    // This is important for reducing binary sizes.
+    noop_visit_mut_type!();
+
+    fn visit_mut_binding_ident(&mut self, i: &mut BindingIdent) {
+        if !self.in_lhs_of_var || self.in_data_fn {
+            self.add_ref(i.id.to_id());
+        }
+    }
+
+    fn visit_mut_assign_pat(&mut self, p: &mut AssignPat) {
+        p.left.visit_mut_with(self);
+        let ids = find_pat_ids(&p.left);
+        self.visit_mut_default_value(&mut p.right, ids);
+    }
+
+    fn visit_mut_assign_pat_prop(&mut self, p: &mut AssignPatProp) {
+        p.key.visit_mut_with(self);
+        if let Some(value) = &mut p.value {
+            self.visit_mut_default_value(value, vec![p.key.id.clone()]);
+        }
+    }
+
+    fn visit_mut_export_named_specifier(&mut self, s: &mut ExportNamedSpecifier) {
+        if let ModuleExportName::Ident(id) = &s.orig {
+            let name = match &s.exported {
+                Some(ModuleExportName::Ident(exported)) => &exported.sym,
+                Some(ModuleExportName::Str(exported)) => &exported.value,
+                None => &id.sym,
+            };
+
+            if !self.state.acts_on(ExportForm::Named) || !self.state.should_remove_specifier(name, id) {
+                self.add_ref(id.to_id());
+            } else if !self.state.config.unexport {
+                // The specifier is removed, along with the local if nothing else uses it.
+                self.state.refs_from_data_fn.insert(id.to_id());
+            }
+        }
+    }
+
+    fn visit_mut_export_decl(&mut self, s: &mut ExportDecl) {
+        let old_in_data = self.in_data_fn;
+
+        match &mut s.decl {
+            Decl::Fn(f) => {
+                if let Ok(should_remove_identifier) = self.state.should_remove_identifier(&f.ident) {
+                    if self.state.acts_on(ExportForm::Decl) && should_remove_identifier {
+                        self.in_data_fn = true;
+                    }
+                }
+                // Kept exports are used by the importers of the module.
+                self.add_ref(f.ident.to_id());
+            }
+
+            // Declarators are removed one by one, like `b` of `export const a = 1, b = 2`.
+            Decl::Var(d) => {
+                for d in d.decls.iter_mut() {
+                    self.visit_mut_exported_declarator(d);
+                }
+
+                return;
+            }
+            _ => {}
+        }
+
+        s.visit_mut_children_with(self);
+
+        self.in_data_fn = old_in_data;
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        if let Expr::Ident(i) = &*e {
+            self.add_ref(i.to_id());
+        }
+    }
+
+    fn visit_mut_jsx_element(&mut self, jsx: &mut JSXElement) {
+        fn get_leftmost_id_member_expr(e: &JSXMemberExpr) -> Id {
+            match &e.obj {
+                JSXObject::Ident(i) => i.to_id(),
+                JSXObject::JSXMemberExpr(e) => get_leftmost_id_member_expr(e),
+            }
+        }
+
+        match &jsx.opening.name {
+            JSXElementName::Ident(i) => {
+                self.add_ref(i.to_id());
+            }
+            JSXElementName::JSXMemberExpr(e) => {
+                self.add_ref(get_leftmost_id_member_expr(e));
+            }
+            _ => {}
+        }
+
+        jsx.visit_mut_children_with(self)
+    }
+
+    fn visit_mut_fn_decl(&mut self, f: &mut FnDecl) {
+        f.visit_mut_children_with(self);
+
+        if self.in_data_fn {
+            self.add_ref(f.ident.to_id());
+        }
+    }
+
+    fn visit_mut_class_decl(&mut self, c: &mut ClassDecl) {
+        c.visit_mut_children_with(self);
+
+        if self.in_data_fn {
+            self.add_ref(c.ident.to_id());
+        }
+    }
+
+    fn visit_mut_fn_expr(&mut self, f: &mut FnExpr) {
+        f.visit_mut_children_with(self);
+
+        if let Some(id) = &f.ident {
+            self.add_ref(id.to_id());
+        }
+    }
+
+    fn visit_mut_module_item(&mut self, s: &mut ModuleItem) {
+        *s = self.analyze_module_item(s.take());
+    }
+
+    fn visit_mut_named_export(&mut self, n: &mut NamedExport) {
+        // Kept specifiers keep their locals alive, even when another alias of the
+        // same local is removed. Re-exported names are not locals.
+        if n.src.is_none() {
+            n.specifiers.visit_mut_with(self);
+        }
+    }
+
+    fn visit_mut_default_decl(&mut self, d: &mut DefaultDecl) {
+        self.check_default(d)
+    }
+
+    fn visit_mut_export_default_expr(&mut self, e: &mut ExportDefaultExpr) {
+        self.check_default(e)
+    }
+
+    /// Decorators using removed exports are removed by the transform, so
+    /// their references don't keep anything.
+    fn visit_mut_decorator(&mut self, d: &mut Decorator) {
+        let old_in_data = self.in_data_fn;
+        self.in_data_fn |= self.state.uses_removed_decls(d);
+        d.visit_mut_children_with(self);
+        self.in_data_fn = old_in_data;
+    }
+
+    fn visit_mut_prop(&mut self, p: &mut Prop) {
+        p.visit_mut_children_with(self);
+
+        if let Prop::Shorthand(i) = &*p {
+            self.add_ref(i.to_id());
+        }
+    }
+
+    fn visit_mut_var_declarator(&mut self, v: &mut VarDeclarator) {
+        let old_in_lhs_of_var = self.in_lhs_of_var;
+
+        self.in_lhs_of_var = true;
+        v.name.visit_mut_with(self);
+
+        self.in_lhs_of_var = false;
+        v.init.visit_mut_with(self);
+
+        self.in_lhs_of_var = old_in_lhs_of_var;
+    }
+}
+
+/// Actual implementation of the transform.
+struct RemoveExportsExprs<C: Comments> {
+    pub state: State,
+    in_lhs_of_var: bool,
+    comments: Option<C>,
+}
+
+impl<C: Comments> RemoveExportsExprs<C> {
+    /// Only top-level bindings are removed, as the bindings of nested scopes
+    /// share the [Id] of the top-level ones they shadow unless `resolver` has
+    /// run.
+    fn should_remove(&self, id: Id) -> bool {
+        self.state.bindings.contains_key(&id)
+            && self.state.refs_from_data_fn.contains(&id)
+            && !self.state.refs_from_other.contains(&id)
+            && !self.state.type_refs.contains(&id)
+            && !self.state.ignored.contains(&id)
+    }
+
+    fn drop_comments(&mut self, span: Span) {
+        let comments = removal_comments(&self.comments, &self.state.config);
+        drop_comments(comments, span, &mut self.state);
+    }
+
+    /// Attaches the comments kept by [Config::keep_comments] to the nodes
+    /// following the removed ones.
+    fn restore_comments(&mut self, m: &Module) {
+        let mut removed = take(&mut self.state.removed_comments);
+        let Some(comments) = &self.comments else {
+            return;
+        };
+        // Otherwise only kept in case strict mode leaves the module untouched.
+        if !self.state.config.keep_comments {
+            return;
+        }
+
+        // Prepended in reverse, so that the comments stay in order.
+        removed.sort_by_key(|(pos, _)| std::cmp::Reverse(*pos));
+
+        for (pos, cmts) in removed {
+            match m.body.iter().map(|i| i.span()).find(|s| !s.is_dummy() && s.lo >= pos) {
+                Some(span) => {
+                    let existing = comments.take_leading(span.lo).unwrap_or_default();
+                    comments.add_leading_comments(span.lo, cmts.into_iter().chain(existing).collect());
+                }
+                None => comments.add_trailing_comments(m.span.hi, cmts),
+            }
+        }
+    }
+
+    /// Leaves a comment naming the exports and declarations removed from the
+    /// `declared` top-level items, on the nodes following them.
+    fn add_tombstones(&self, m: &Module, declared: Vec<(BytePos, Manifest)>) {
+        let Some(comments) = &self.comments else {
+            return;
+        };
+        let left = Manifest::of(&m.body);
+
+        for (pos, declared) in declared {
+            let mut names = difference(declared.exports, &left.exports);
+            for name in difference(declared.declarations, &left.declarations) {
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+            if names.is_empty() || pos == BytePos::DUMMY {
+                continue;
+            }
+
+            let comment = Comment {
+                kind: CommentKind::Block,
+                span: DUMMY_SP,
+                text: format!(" removed by remove-export: {} ", names.iter().map(|n| &**n).collect::<Vec<_>>().join(", ")).into(),
+            };
+            match m.body.iter().map(|i| i.span()).find(|s| !s.is_dummy() && s.lo >= pos) {
+                Some(span) => comments.add_leading(span.lo, comment),
+                None => comments.add_trailing(m.span.hi, comment),
+            }
+        }
+    }
+
+    /// Puts the comments of removed nodes back at their positions, as the
+    /// module is left untouched by [Config::strict].
+    fn put_back_comments(&mut self) {
+        let removed = take(&mut self.state.removed_comments);
+        let Some(comments) = &self.comments else {
+            return;
+        };
+
+        for (pos, cmts) in removed {
+            comments.add_leading_comments(pos, cmts);
+        }
+    }
+
+    /// Creates an empty function replacing the function described by `info` at
+    /// `span`, async or generator like it so callers awaiting or iterating it
+    /// still work.
+    fn create_empty_fn(&mut self, span: Span, info: DeclInfo) -> FnExpr {
+        let config = &self.state.config;
+        if config.pure_annotations && !config.dry_run && !span.is_dummy() {
+            self.comments.add_pure_comment(span.lo);
+        }
+
+        FnExpr {
+            ident: None,
+            function: Box::new(Function {
+                ctxt: SyntaxContext::empty(),
+                params: vec![],
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![],
+                    ctxt: SyntaxContext::empty(),
+                }),
+                span,
+                is_generator: info.is_generator,
+                is_async: info.is_async,
+                decorators: vec![],
+                return_type: None,
+                type_params: None,
+            })
+        }
+    }
+}
+
+/// Returns `foo` of `exports.foo` and `module.exports.foo`.
+fn commonjs_export_name(m: &MemberExpr) -> Option<&Atom> {
+    let name = match &m.prop {
+        MemberProp::Ident(i) => &i.sym,
+        MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
+            Expr::Lit(Lit::Str(s)) => &s.value,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    match &*m.obj {
+        Expr::Ident(i) if i.sym == "exports" => Some(name),
+        Expr::Member(obj) if is_module_exports(obj) => Some(name),
+        _ => None,
+    }
+}
+
+/// Whether `e` is `module.hot` or `import.meta.hot`.
+fn is_hot(e: &Expr) -> bool {
+    let Expr::Member(m) = e else {
+        return false;
+    };
+
+    matches!(&m.prop, MemberProp::Ident(p) if p.sym == "hot")
+        && match &*m.obj {
+            Expr::Ident(i) => i.sym == "module",
+            Expr::MetaProp(p) => p.kind == MetaPropKind::ImportMeta,
+            _ => false,
+        }
+}
+
+/// Whether `s` registers HMR callbacks, like `module.hot.accept(...)`,
+/// `module.hot && module.hot.accept(...)` or `if (import.meta.hot) { ... }`.
+fn is_hmr_stmt(s: &Stmt) -> bool {
+    match s {
+        Stmt::If(IfStmt { test, .. }) => is_hot(test),
+        Stmt::Expr(ExprStmt { expr, .. }) => match &**expr {
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                ..
+            }) => matches!(&**callee, Expr::Member(m) if is_hot(&m.obj)),
+            Expr::Bin(BinExpr {
+                op: op!("&&"), left, ..
+            }) => is_hot(left),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns the dotted path of `m`, like `globalThis.__DATA_FN__`.
+fn member_path(m: &MemberExpr) -> Option<String> {
+    let obj = match &*m.obj {
+        Expr::Ident(i) => i.sym.to_string(),
+        Expr::Member(m) => member_path(m)?,
+        _ => return None,
+    };
+    let prop = match &m.prop {
+        MemberProp::Ident(i) => &i.sym,
+        MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
+            Expr::Lit(Lit::Str(s)) => &s.value,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(format!("{}.{}", obj, prop))
+}
+
+/// Returns `foo` of `Object.defineProperty(exports, "foo", { ... })`.
+fn defined_export_name(e: &Expr) -> Option<&Atom> {
+    let Expr::Call(CallExpr {
+        callee: Callee::Expr(callee),
+        args,
+        ..
+    }) = e
+    else {
+        return None;
+    };
+    let Expr::Member(callee) = &**callee else {
+        return None;
+    };
+    if !matches!(&*callee.obj, Expr::Ident(i) if i.sym == "Object")
+        || !matches!(&callee.prop, MemberProp::Ident(p) if p.sym == "defineProperty")
+    {
+        return None;
+    }
+
+    match args.as_slice() {
+        [ExprOrSpread { spread: None, expr: target }, ExprOrSpread { spread: None, expr: name }, ..] => {
+            match (&**target, &**name) {
+                (Expr::Ident(t), Expr::Lit(Lit::Str(name))) if t.sym == "exports" => Some(&name.value),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the targets of the `exports.foo = exports.bar = void 0` chain `e`.
+fn void_export_chain(e: &Expr) -> Option<Vec<MemberExpr>> {
+    let mut targets = vec![];
+    let mut e = e;
+
+    loop {
+        match e {
+            Expr::Assign(AssignExpr {
+                op: op!("="),
+                left: AssignTarget::Simple(SimpleAssignTarget::Member(left)),
+                right,
+                ..
+            }) if commonjs_export_name(left).is_some() => {
+                targets.push(left.clone());
+                e = right;
+            }
+            Expr::Unary(UnaryExpr { op: op!("void"), .. }) if !targets.is_empty() => return Some(targets),
+            _ => return None,
+        }
+    }
+}
+
+/// Whether `m` is `module.exports`.
+fn is_module_exports(m: &MemberExpr) -> bool {
+    matches!(&*m.obj, Expr::Ident(i) if i.sym == "module")
+        && matches!(&m.prop, MemberProp::Ident(p) if p.sym == "exports")
+}
+
+fn prop_name(key: &PropName) -> Option<&Atom> {
+    match key {
+        PropName::Ident(i) => Some(&i.sym),
+        PropName::Str(s) => Some(&s.value),
+        _ => None,
+    }
+}
+
+/// Whether `p` has an object rest element, like `{ a, ...rest }`.
+fn has_object_rest(p: &Pat) -> bool {
+    match p {
+        Pat::Object(o) => o.props.iter().any(|prop| match prop {
+            ObjectPatProp::Rest(..) => true,
+            ObjectPatProp::KeyValue(kv) => has_object_rest(&kv.value),
+            ObjectPatProp::Assign(..) => false,
+        }),
+        Pat::Array(a) => a.elems.iter().flatten().any(has_object_rest),
+        Pat::Assign(a) => has_object_rest(&a.left),
+        _ => false,
+    }
+}
+
+/// Creates `export const name = init`.
+fn export_stub(span: Span, name: Ident, init: Box<Expr>) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+        span,
+        decl: Decl::Var(Box::new(VarDecl {
+            span: DUMMY_SP,
+            ctxt: SyntaxContext::empty(),
+            kind: VarDeclKind::Const,
+            declare: false,
+            decls: vec![VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(name.into()),
+                init: Some(init),
+                definite: false,
+            }],
+        })),
+    }))
+}
+
+/// Parses an expression given in the options.
+fn parse_expr(src: &str) -> Option<Box<Expr>> {
+    let cm = SourceMap::default();
+    let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
 
-    cur_declaring: FxHashSet<Id>,
+    match parse_file_as_expr(&fm, Syntax::default(), EsVersion::latest(), None, &mut vec![]) {
+        Ok(expr) => Some(drop_span(expr)),
+        Err(err) => {
+            warn(&format!("remove-export: invalid expression `{}`: {}", src, err.kind().msg()));
+            None
+        }
+    }
+}
 
-    should_run_again: bool,
-    remove_exports: Vec<String>,
+fn undefined() -> Box<Expr> {
+    Box::new(Expr::Ident(Ident::new_no_ctxt("undefined".into(), DUMMY_SP)))
 }
 
-impl State {
-    fn should_remove_identifier(&mut self, i: &Ident) -> Result<bool, Error> {
-        Ok(self.remove_exports.contains(&String::from(&*i.sym)))
+/// Drops the comments attached to a removed node, so they don't end up on its
+/// neighbors, or keeps them in `state` with [Config::keep_comments] and
+/// [Config::strict].
+fn drop_comments(comments: &dyn Comments, span: Span, state: &mut State) {
+    let cmts: Vec<_> = comments
+        .take_leading(span.lo)
+        .into_iter()
+        .chain(comments.take_trailing(span.hi))
+        .flatten()
+        .collect();
+
+    if (state.config.keep_comments || state.config.strict) && !cmts.is_empty() {
+        state.removed_comments.push((span.lo, cmts));
     }
-    fn should_remove_default(&mut self) -> bool {
-        self.remove_exports.contains(&String::from("default"))
+}
+
+/// Comments to drop those of removed nodes from, which are left untouched by
+/// dry runs.
+fn removal_comments<'a>(comments: &'a dyn Comments, config: &Config) -> &'a dyn Comments {
+    if config.dry_run {
+        &NoopComments
+    } else {
+        comments
     }
 }
 
-struct Analyzer<'a> {
-    state: &'a mut State,
-    in_lhs_of_var: bool,
-    in_data_fn: bool,
+#[derive(Debug)]
+enum NamePattern {
+    Glob(Pattern),
+    Regex(Regex),
 }
 
-impl Analyzer<'_> {
-    fn add_ref(&mut self, id: Id) {
-        tracing::trace!("add_ref({}{:?}, data = {})", id.0, id.1, self.in_data_fn);
-        if self.in_data_fn {
-            self.state.refs_from_data_fn.insert(id);
-        } else {
-            if self.state.cur_declaring.contains(&id) {
-                return;
+impl NamePattern {
+    fn new(kind: PatternKind, pattern: &str) -> Option<Self> {
+        let compiled = match kind {
+            PatternKind::Glob => Pattern::new(pattern)
+                .map(NamePattern::Glob)
+                .map_err(|err| err.to_string()),
+            PatternKind::Regex => Regex::new(pattern)
+                .map(NamePattern::Regex)
+                .map_err(|err| err.to_string()),
+        };
+
+        match compiled {
+            Ok(p) => Some(p),
+            Err(err) => {
+                tracing::warn!("remove_export_exprs: invalid pattern `{}`: {}", pattern, err);
+                None
             }
+        }
+    }
 
-            self.state.refs_from_other.insert(id);
+    fn matches(&self, name: &str) -> bool {
+        match self {
+            NamePattern::Glob(p) => p.matches(name),
+            NamePattern::Regex(r) => r.is_match(name),
         }
     }
+}
 
-    fn check_default<T:FoldWith<Self>>(&mut self, e: T) -> T {
-        if self.state.should_remove_default() {
-            
-            let old_in_data = self.in_data_fn;
+/// Returns the `exports` of `config` which are not glob patterns.
+fn export_names(config: &Config) -> FxHashSet<Atom> {
+    config
+        .exports
+        .iter()
+        .filter(|e| !e.contains(['*', '?', '[']) && !e.starts_with("default:"))
+        .map(|e| Atom::from(&**e))
+        .collect()
+}
 
-            self.in_data_fn = true;
-    
-            let e = e.fold_children_with(self);
-    
-            self.in_data_fn = old_in_data;
-    
-            return e
-        }
+/// Returns the names of the `default:Name` entries of the `exports` of
+/// `config`.
+fn default_locals(config: &Config) -> FxHashSet<Atom> {
+    config
+        .exports
+        .iter()
+        .filter_map(|e| e.strip_prefix("default:"))
+        .map(Atom::from)
+        .collect()
+}
 
-        return e.fold_children_with(self);
+/// Parses the `replaceWith` or `stub` of `config`.
+fn stub(config: &Config) -> Option<Box<Expr>> {
+    config.replace_with.as_deref().or(config.stub.as_deref()).and_then(parse_expr)
+}
+
+/// Parses the custom `defaultReplacement` of `config`.
+fn default_expr(config: &Config) -> Option<Box<Expr>> {
+    match &config.default_replacement {
+        DefaultReplacement::Expr(src) => parse_expr(src),
+        _ => None,
     }
 }
 
-impl Fold for Analyzer<'_> {
-    // This is important for reducing binary sizes.
-    noop_fold_type!();
+/// Compiles the glob patterns among the `exports` of `config`, and its
+/// `patterns`.
+fn export_patterns(config: &Config) -> Vec<NamePattern> {
+    let globs = config
+        .exports
+        .iter()
+        .filter(|e| e.contains(['*', '?', '[']))
+        .filter_map(|e| NamePattern::new(PatternKind::Glob, e));
+    let patterns = config
+        .patterns
+        .iter()
+        .filter_map(|p| NamePattern::new(config.kind, p));
+
+    globs.chain(patterns).collect()
+}
 
-    fn fold_binding_ident(&mut self, i: BindingIdent) -> BindingIdent {
-        if !self.in_lhs_of_var || self.in_data_fn {
-            self.add_ref(i.id.to_id());
-        }
+/// Comments which protect the declaration they lead from removal.
+const IGNORE_COMMENTS: &[&str] = &["swc-remove-export-ignore", "@keep"];
 
-        i
-    }
+/// Comment disabling the transform for the module it leads.
+const DISABLE_COMMENT: &str = "swc-remove-export-disable";
 
-    fn fold_export_named_specifier(&mut self, s: ExportNamedSpecifier) -> ExportNamedSpecifier {
-        if let ModuleExportName::Ident(id) = &s.orig {
-            if !self.state.remove_exports.contains(&String::from(&*id.sym)) {
-                self.add_ref(id.to_id());
-            }
-        }
+/// Prefix of the comment listing the exports kept in the module it leads,
+/// like `/* swc-remove-export: keep getStaticProps, getConfig */`.
+const KEEP_COMMENT: &str = "swc-remove-export: keep";
 
-        s
+/// Returns the names of the exports kept by the [KEEP_COMMENT]s leading `m`,
+/// or `None` if `m` is led by [DISABLE_COMMENT].
+fn file_directives(m: &Module, comments: &dyn Comments) -> Option<FxHashSet<Atom>> {
+    let mut kept = FxHashSet::default();
+    let Some(first) = m.body.first() else {
+        return Some(kept);
+    };
+
+    for c in comments.get_leading(first.span().lo).unwrap_or_default() {
+        let text = c.text.trim();
+        if text == DISABLE_COMMENT {
+            return None;
+        }
+        if let Some(names) = text.strip_prefix(KEEP_COMMENT) {
+            kept.extend(
+                names
+                    .split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|n| !n.is_empty())
+                    .map(Atom::from),
+            );
+        }
     }
 
-    fn fold_export_decl(&mut self, s: ExportDecl) -> ExportDecl {
-        let old_in_data = self.in_data_fn;
+    Some(kept)
+}
 
-        match &s.decl {
-            Decl::Fn(f) => {
-                if let Ok(should_remove_identifier) = self.state.should_remove_identifier(&f.ident) {
-                    if should_remove_identifier {
-                        self.in_data_fn = true;
-                        self.add_ref(f.ident.to_id());
-                    }
-                }
+/// Returns the names of the exports declared by `m` which are led by a comment
+/// with one of the JSDoc `tags`, like `/** @removeOnClient */`.
+fn tagged_exports(m: &Module, comments: &dyn Comments, tags: &[String]) -> FxHashSet<Atom> {
+    let mut names = FxHashSet::default();
+
+    for item in &m.body {
+        let (span, names_of_item) = match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { span, decl })) => (*span, decl_names(decl)),
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { span, .. }))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { span, .. })) => {
+                (*span, vec![atom!("default")])
             }
+            _ => continue,
+        };
 
-            Decl::Var(d) => {    
-                if d.decls.is_empty() {
-                    return s;
-                }
-                if let Pat::Ident(id) = &d.decls[0].name {
-                    if self.state.remove_exports.contains(&String::from(&*id.id.sym)) {
-                        self.in_data_fn = true;
-                        self.add_ref(id.to_id());
-                    }
-                }
-            }
-            _ => {}
+        let tagged = comments.get_leading(span.lo).is_some_and(|leading| {
+            leading.iter().any(|c| {
+                c.text.split(|c: char| c.is_whitespace() || c == '*').any(|word| {
+                    word.strip_prefix('@').is_some_and(|tag| tags.iter().any(|t| t == tag))
+                })
+            })
+        });
+        if tagged {
+            names.extend(names_of_item);
         }
+    }
 
-        let e = s.fold_children_with(self);
-
-        self.in_data_fn = old_in_data;
+    names
+}
 
-        return e;
+/// Returns the names of the exports declared by `m` whose initializer calls
+/// one of the `factories`, like `createServerFn(...)` or
+/// `createServerFn(...).handler(...)`.
+fn factory_exports(m: &Module, factories: &[String]) -> Vec<Atom> {
+    fn factory(e: &Expr) -> Option<&Atom> {
+        match e {
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                ..
+            }) => match &**callee {
+                Expr::Ident(i) => Some(&i.sym),
+                callee => factory(callee),
+            },
+            Expr::Member(m) => factory(&m.obj),
+            Expr::Await(a) => factory(&a.arg),
+            Expr::Paren(p) => factory(&p.expr),
+            _ => None,
+        }
     }
 
-    fn fold_expr(&mut self, e: Expr) -> Expr {
-        let e = e.fold_children_with(self);
+    m.body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl: Decl::Var(v), .. })) => Some(v),
+            _ => None,
+        })
+        .flat_map(|v| &v.decls)
+        .filter(|d| {
+            d.init
+                .as_deref()
+                .and_then(factory)
+                .is_some_and(|f| factories.iter().any(|name| name == &**f))
+        })
+        .flat_map(|d| find_pat_ids::<_, Ident>(&d.name))
+        .map(|id| id.sym)
+        .collect()
+}
 
-        if let Expr::Ident(i) = &e {
-            self.add_ref(i.to_id());
-        }
+/// Returns the bindings declared by top-level declarations of `m` which are
+/// led by one of [IGNORE_COMMENTS].
+fn ignored_bindings(m: &Module, comments: &dyn Comments) -> FxHashSet<Id> {
+    let mut ids = FxHashSet::default();
 
-        e
-    }
+    for item in &m.body {
+        let (span, decl) = match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { span, decl })) => (*span, decl),
+            ModuleItem::Stmt(Stmt::Decl(decl)) => (decl.span(), decl),
+            _ => continue,
+        };
 
-    fn fold_jsx_element(&mut self, jsx: JSXElement) -> JSXElement {
-        fn get_leftmost_id_member_expr(e: &JSXMemberExpr) -> Id {
-            match &e.obj {
-                JSXObject::Ident(i) => i.to_id(),
-                JSXObject::JSXMemberExpr(e) => get_leftmost_id_member_expr(e),
-            }
+        let ignored = comments.get_leading(span.lo).is_some_and(|leading| {
+            leading
+                .iter()
+                .any(|c| IGNORE_COMMENTS.contains(&c.text.trim()))
+        });
+        if !ignored {
+            continue;
         }
 
-        match &jsx.opening.name {
-            JSXElementName::Ident(i) => {
-                self.add_ref(i.to_id());
-            }
-            JSXElementName::JSXMemberExpr(e) => {
-                self.add_ref(get_leftmost_id_member_expr(e));
+        match decl {
+            Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }) => {
+                ids.insert(ident.to_id());
             }
+            Decl::Var(v) => ids.extend(find_pat_ids::<_, Id>(&v.decls)),
             _ => {}
         }
-
-        jsx.fold_children_with(self)
     }
 
-    fn fold_fn_decl(&mut self, f: FnDecl) -> FnDecl {
+    ids
+}
 
-        let f = f.fold_children_with(self);
+/// Returns the top-level type declarations of `m` which don't share their name
+/// with a value.
+fn type_only_decls(m: &Module) -> FxHashSet<Id> {
+    let mut types = FxHashSet::default();
+    let mut values = FxHashSet::default();
+
+    for item in &m.body {
+        let decl = match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(i)) => {
+                if !i.type_only {
+                    values.extend(i.specifiers.iter().filter_map(|s| match s {
+                        ImportSpecifier::Named(ImportNamedSpecifier {
+                            local,
+                            is_type_only: false,
+                            ..
+                        })
+                        | ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                        | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                            Some(local.to_id())
+                        }
+                        _ => None,
+                    }));
+                }
+                continue;
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. }))
+            | ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+            _ => continue,
+        };
 
-        if self.in_data_fn {
-            self.add_ref(f.ident.to_id());
+        match decl {
+            Decl::TsInterface(i) => {
+                types.insert(i.id.to_id());
+            }
+            Decl::TsTypeAlias(a) => {
+                types.insert(a.id.to_id());
+            }
+            Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }) => {
+                values.insert(ident.to_id());
+            }
+            Decl::TsEnum(e) => {
+                values.insert(e.id.to_id());
+            }
+            Decl::Var(v) => values.extend(find_pat_ids::<_, Id>(&v.decls)),
+            _ => {}
         }
-
-        f
     }
 
-    fn fold_fn_expr(&mut self, f: FnExpr) -> FnExpr {
-        let f = f.fold_children_with(self);
+    types.retain(|id| !values.contains(id));
+    types
+}
 
-        if let Some(id) = &f.ident {
-            self.add_ref(id.to_id());
-        }
+/// Returns the bindings of the imports of `m`.
+fn imported_bindings(m: &Module) -> FxHashSet<Id> {
+    m.body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(i)) => Some(i),
+            _ => None,
+        })
+        .flat_map(|i| &i.specifiers)
+        .map(|s| match s {
+            ImportSpecifier::Named(ImportNamedSpecifier { local, .. })
+            | ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+            | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => local.to_id(),
+        })
+        .collect()
+}
 
-        f
+/// Returns the bindings declared at the top level of `m`.
+fn top_level_bindings(m: &Module) -> FxHashMap<Id, DeclInfo> {
+    let mut ids = FxHashMap::default();
+    let other = DeclInfo::default();
+
+    for item in &m.body {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(i)) => {
+                ids.extend(i.specifiers.iter().map(|s| match s {
+                    ImportSpecifier::Named(ImportNamedSpecifier { local, .. })
+                    | ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                    | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                        (local.to_id(), other)
+                    }
+                }));
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. }))
+            | ModuleItem::Stmt(Stmt::Decl(decl)) => match decl {
+                Decl::Class(c) => {
+                    ids.insert(
+                        c.ident.to_id(),
+                        DeclInfo {
+                            kind: ExportKind::Class,
+                            ..other
+                        },
+                    );
+                }
+                Decl::Fn(f) => {
+                    ids.insert(
+                        f.ident.to_id(),
+                        DeclInfo::of_function(ExportKind::Fn, &f.function),
+                    );
+                }
+                Decl::Var(v) => {
+                    for d in &v.decls {
+                        let info = match (&d.name, &d.init) {
+                            (Pat::Ident(..), Some(init)) => DeclInfo::of_expr(ExportKind::Var, init),
+                            _ => DeclInfo {
+                                kind: ExportKind::Var,
+                                ..other
+                            },
+                        };
+                        ids.extend(find_pat_ids::<_, Id>(&d.name).into_iter().map(|id| (id, info)));
+                    }
+                }
+                Decl::TsInterface(i) => {
+                    ids.insert(
+                        i.id.to_id(),
+                        DeclInfo {
+                            kind: ExportKind::Type,
+                            ..other
+                        },
+                    );
+                }
+                Decl::TsTypeAlias(a) => {
+                    ids.insert(
+                        a.id.to_id(),
+                        DeclInfo {
+                            kind: ExportKind::Type,
+                            ..other
+                        },
+                    );
+                }
+                Decl::TsEnum(e) => {
+                    ids.insert(e.id.to_id(), other);
+                }
+                Decl::TsModule(m) => {
+                    if let TsModuleName::Ident(i) = &m.id {
+                        ids.insert(i.to_id(), other);
+                    }
+                }
+                _ => {}
+            },
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(e)) => match &e.decl {
+                DefaultDecl::Class(ClassExpr { ident: Some(i), .. }) => {
+                    ids.insert(
+                        i.to_id(),
+                        DeclInfo {
+                            kind: ExportKind::Class,
+                            ..other
+                        },
+                    );
+                }
+                DefaultDecl::Fn(FnExpr {
+                    ident: Some(i),
+                    function,
+                }) => {
+                    ids.insert(i.to_id(), DeclInfo::of_function(ExportKind::Fn, function));
+                }
+                _ => {}
+            },
+            ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(i)) => {
+                ids.insert(i.id.to_id(), other);
+            }
+            _ => {}
+        }
     }
 
-    /// Drops [ExportDecl] if all specifiers are removed.
-    fn fold_module_item(&mut self, s: ModuleItem) -> ModuleItem {
-        match s {
-            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(e)) if !e.specifiers.is_empty() => {
-                let e = e.fold_with(self);
+    ids
+}
 
-                if e.specifiers.is_empty() {
-                    return ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span: DUMMY_SP }));
-                }
+/// Returns the declaration of the default export of `m`.
+fn default_decl(m: &Module) -> DeclInfo {
+    let mut info = DeclInfo::default();
 
-                return ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(e));
+    for item in &m.body {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(e)) => {
+                if let DefaultDecl::Fn(f) = &e.decl {
+                    info = DeclInfo::of_function(ExportKind::Default, &f.function);
+                }
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(e)) => {
+                info = DeclInfo::of_expr(ExportKind::Default, &e.expr);
             }
             _ => {}
-        };
+        }
+    }
 
-        // Visit children to ensure that all references is added to the scope.
-        let s = s.fold_children_with(self);
+    DeclInfo {
+        kind: ExportKind::Default,
+        ..info
+    }
+}
 
-        if let ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(e)) = &s {
-            match &e.decl {
-                Decl::Fn(f) => {
-                    if let Ok(should_remove_identifier) = self.state.should_remove_identifier(&f.ident) {
-                        if should_remove_identifier {
-                            return ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span: DUMMY_SP }));
-                        }
-                    } else {
-                        return s;
-                    }
-                }
+/// Returns the name of the function, class or binding exported as `default` by
+/// `m`.
+fn default_local(m: &Module) -> Option<Atom> {
+    m.body.iter().find_map(|item| match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(e)) => match &e.decl {
+            DefaultDecl::Fn(FnExpr { ident: Some(i), .. }) | DefaultDecl::Class(ClassExpr { ident: Some(i), .. }) => {
+                Some(i.sym.clone())
+            }
+            _ => None,
+        },
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(e)) => default_expr_name(&e.expr),
+        _ => None,
+    })
+}
 
-                Decl::Var(d) => {
-                    if d.decls.is_empty() {
-                        return ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span: DUMMY_SP }));
+/// Returns the name of `export default e`, either the binding `e` or the name
+/// of the function or class expression `e`, like `(function getData() {})`.
+fn default_expr_name(e: &Expr) -> Option<Atom> {
+    match e {
+        Expr::Ident(i) | Expr::Fn(FnExpr { ident: Some(i), .. }) | Expr::Class(ClassExpr { ident: Some(i), .. }) => {
+            Some(i.sym.clone())
+        }
+        Expr::Paren(p) => default_expr_name(&p.expr),
+        _ => None,
+    }
+}
+
+/// Warns about local `export { x }` specifiers whose `x` is not declared nor
+/// imported, which usually means a broken upstream transform.
+fn warn_phantom_exports(m: &Module) {
+    let bindings = top_level_bindings(m);
+
+    for item in &m.body {
+        if let ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+            specifiers,
+            src: None,
+            ..
+        })) = item
+        {
+            for s in specifiers {
+                if let ExportSpecifier::Named(ExportNamedSpecifier {
+                    orig: ModuleExportName::Ident(orig),
+                    ..
+                }) = s
+                {
+                    if !bindings.contains_key(&orig.to_id()) {
+                        tracing::warn!(
+                            "remove_export_exprs: `{}` is exported but never declared",
+                            orig.sym
+                        );
                     }
                 }
-                _ => {}
             }
         }
+    }
+}
 
-        s
+/// Warns about the `exports` of `config` which match none of the exports of
+/// `m`.
+fn warn_unmatched_exports(m: &Module, config: &Config) {
+    if m
+        .body
+        .iter()
+        .any(|i| matches!(i, ModuleItem::ModuleDecl(ModuleDecl::ExportAll(..))))
+    {
+        return;
     }
 
-    fn fold_named_export(&mut self, mut n: NamedExport) -> NamedExport {
-        if n.src.is_some() {
-            n.specifiers = n.specifiers.fold_with(self);
-        }
+    let exports = Manifest::of(&m.body).exports;
+    let unmatched = config
+        .exports
+        .iter()
+        .filter(|e| {
+            !exports
+                .iter()
+                .any(|name| {
+                    let e = if e.starts_with("default:") { "default" } else { e };
+                    Pattern::new(e).is_ok_and(|p| p.matches(name))
+                })
+        })
+        .map(|e| format!("`{}`", e))
+        .collect::<Vec<_>>();
+
+    if !unmatched.is_empty() {
+        warn(&format!(
+            "remove-export: configured exports not found: {}",
+            unmatched.join(", ")
+        ));
+    }
+}
 
-        n
+/// Emits a warning through the diagnostics handler of swc, or logs it if
+/// there is none.
+fn warn(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.warn(msg));
+    } else {
+        tracing::warn!("{}", msg);
     }
+}
 
-    fn fold_default_decl(&mut self, d: DefaultDecl) -> DefaultDecl {
-        return self.check_default(d);
+/// Emits an error through the diagnostics handler of swc, or logs it if there
+/// is none.
+fn error(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.err(msg));
+    } else {
+        tracing::error!("{}", msg);
     }
+}
+
+/// Collects bindings assigned to by the visited code.
+#[derive(Default)]
+struct AssignedIds {
+    ids: FxHashSet<Id>,
+}
+
+impl Visit for AssignedIds {
+    noop_visit_type!();
 
-    fn fold_export_default_expr(&mut self, e: ExportDefaultExpr) -> ExportDefaultExpr {
-        return self.check_default(e);
+    fn visit_assign_expr(&mut self, e: &AssignExpr) {
+        e.visit_children_with(self);
+
+        match &e.left {
+            AssignTarget::Simple(SimpleAssignTarget::Ident(i)) => {
+                self.ids.insert(i.to_id());
+            }
+            AssignTarget::Pat(p) => {
+                self.ids.extend(find_pat_ids::<_, Id>(p));
+            }
+            _ => {}
+        }
     }
 
-    fn fold_prop(&mut self, p: Prop) -> Prop {
-        let p = p.fold_children_with(self);
+    fn visit_update_expr(&mut self, e: &UpdateExpr) {
+        e.visit_children_with(self);
 
-        if let Prop::Shorthand(i) = &p {
-            self.add_ref(i.to_id());
+        if let Expr::Ident(i) = &*e.arg {
+            self.ids.insert(i.to_id());
         }
+    }
+}
 
-        p
+/// Returns the name of the namespace `m`, unless it is ambient or a module
+/// like `declare module 'foo'`.
+fn namespace_id(m: &TsModuleDecl) -> Option<&Ident> {
+    match &m.id {
+        TsModuleName::Ident(id) if !m.declare => Some(id),
+        _ => None,
     }
+}
 
-    fn fold_var_declarator(&mut self, mut v: VarDeclarator) -> VarDeclarator {
-        let old_in_lhs_of_var = self.in_lhs_of_var;
+/// Returns the binding reassigned by the statement `s`, like `foo` of
+/// `foo = wrap(foo)` or `foo++`.
+fn reassigned_binding(s: &Stmt) -> Option<Id> {
+    let Stmt::Expr(ExprStmt { expr, .. }) = s else {
+        return None;
+    };
+
+    match &**expr {
+        Expr::Assign(AssignExpr {
+            left: AssignTarget::Simple(SimpleAssignTarget::Ident(i)),
+            ..
+        }) => Some(i.to_id()),
+        Expr::Update(UpdateExpr { arg, .. }) => match &**arg {
+            Expr::Ident(i) => Some(i.to_id()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
 
-        self.in_lhs_of_var = true;
-        v.name = v.name.fold_with(self);
+/// Returns `roots` along with the identifiers they reach through `deps`.
+fn reachable(deps: &FxHashMap<Id, FxHashSet<Id>>, roots: FxHashSet<Id>) -> FxHashSet<Id> {
+    let mut stack = roots.iter().cloned().collect::<Vec<_>>();
+    let mut ids = roots;
 
-        self.in_lhs_of_var = false;
-        v.init = v.init.fold_with(self);
+    while let Some(id) = stack.pop() {
+        for dep in deps.get(&id).into_iter().flatten() {
+            if ids.insert(dep.clone()) {
+                stack.push(dep.clone());
+            }
+        }
+    }
 
-        self.in_lhs_of_var = old_in_lhs_of_var;
-        v
+    ids
+}
+
+/// Returns the identifiers referenced by the types in `n`.
+fn type_refs<N: VisitWith<TypeRefCollector>>(n: &N) -> FxHashSet<Id> {
+    let mut v = TypeRefCollector::default();
+    n.visit_with(&mut v);
+    v.ids
+}
+
+#[derive(Default)]
+struct TypeRefCollector {
+    ids: FxHashSet<Id>,
+}
+
+/// Unlike the other visitors, this one visits types.
+impl Visit for TypeRefCollector {
+    fn visit_ts_entity_name(&mut self, n: &TsEntityName) {
+        if let TsEntityName::Ident(i) = n {
+            self.ids.insert(i.to_id());
+        }
+
+        n.visit_children_with(self);
     }
 }
 
-/// Actual implementation of the transform.
-struct RemoveExportsExprs {
-    pub state: State,
-    in_lhs_of_var: bool,
+/// Whether evaluating `e` may have side effects, like calls. Functions are not
+/// looked into, as they are not called by their definition.
+fn has_side_effects(e: &Expr) -> bool {
+    let mut v = SideEffects::default();
+    e.visit_with(&mut v);
+    v.found
 }
 
-impl RemoveExportsExprs {
-    fn should_remove(&self, id: Id) -> bool {
-        self.state.refs_from_data_fn.contains(&id) && !self.state.refs_from_other.contains(&id)
+#[derive(Default)]
+struct SideEffects {
+    found: bool,
+}
+
+impl Visit for SideEffects {
+    noop_visit_type!();
+
+    fn visit_expr(&mut self, e: &Expr) {
+        match e {
+            Expr::Call(..)
+            | Expr::New(..)
+            | Expr::Await(..)
+            | Expr::Yield(..)
+            | Expr::Update(..)
+            | Expr::Assign(..)
+            | Expr::TaggedTpl(..) => self.found = true,
+            Expr::OptChain(c) if c.base.is_call() => self.found = true,
+            _ => e.visit_children_with(self),
+        }
     }
 
-    /// Mark identifiers in `n` as a candidate for removal.
-    fn mark_as_candidate<N>(&mut self, n: N) -> N
-    where
-        N: for<'aa> FoldWith<Analyzer<'aa>>,
-    {
-        tracing::debug!("mark_as_candidate");
-
-        // Analyzer never change `in_data_fn` to false, so all identifiers in `n` will
-        // be marked as referenced from a data function.
-        let mut v = Analyzer {
-            state: &mut self.state,
-            in_lhs_of_var: false,
-            in_data_fn: true,
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_class(&mut self, _: &Class) {}
+}
+
+/// Replaces the `typeof x` types of removed bindings `x`, like in
+/// `ReturnType<typeof getStaticProps>`, with `any`.
+struct WidenTypeQueries<'a> {
+    removed: &'a FxHashSet<Id>,
+    /// Bindings declared by the output, which may shadow removed ones.
+    declared: AHashSet<Id>,
+}
+
+impl VisitMut for WidenTypeQueries<'_> {
+    fn visit_mut_ts_type(&mut self, t: &mut TsType) {
+        t.visit_mut_children_with(self);
+
+        let TsType::TsTypeQuery(TsTypeQuery {
+            span,
+            expr_name: TsTypeQueryExpr::TsEntityName(name),
+            ..
+        }) = t
+        else {
+            return;
         };
 
-        let n = n.fold_with(&mut v);
-        self.state.should_run_again = true;
-        n
+        let mut name = &*name;
+        while let TsEntityName::TsQualifiedName(q) = name {
+            name = &q.left;
+        }
+        if let TsEntityName::Ident(i) = name {
+            if self.removed.contains(&i.to_id()) && !self.declared.contains(&i.to_id()) {
+                *t = TsType::TsKeywordType(TsKeywordType {
+                    span: *span,
+                    kind: TsKeywordTypeKind::TsAnyKeyword,
+                });
+            }
+        }
     }
+}
 
-    fn create_empty_fn(&mut self) -> FnExpr {
-        return FnExpr {
-            ident: None,
-            function: Box::new(Function {
-                ctxt: SyntaxContext::empty(),
-                params: vec![],
-                body: Some(BlockStmt {
-                    span: DUMMY_SP,
-                    stmts: vec![],
-                    ctxt: SyntaxContext::empty(),
-                }),
-                span: DUMMY_SP,
-                is_generator: false,
-                is_async: false,
-                decorators: vec![],
-                return_type: None,
-                type_params: None,
-            })
-        };
+/// Collects the identifiers referenced by the visited nodes, leaving out
+/// the names which aren't references: imported names, labels, and the names
+/// of function and class expressions along with their uses inside them.
+#[derive(Default)]
+struct IdentCollector {
+    ids: FxHashSet<Id>,
+}
+
+impl IdentCollector {
+    /// Collects the references of `n` other than to its own `name`.
+    fn visit_named<N: VisitWith<Self>>(&mut self, name: Option<&Ident>, n: &N) {
+        let mut inner = IdentCollector::default();
+        n.visit_with(&mut inner);
+        if let Some(name) = name {
+            inner.ids.remove(&name.to_id());
+        }
+        self.ids.extend(inner.ids);
+    }
+}
+
+impl Visit for IdentCollector {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, i: &Ident) {
+        self.ids.insert(i.to_id());
+    }
+
+    fn visit_import_named_specifier(&mut self, s: &ImportNamedSpecifier) {
+        s.local.visit_with(self);
+    }
+
+    fn visit_labeled_stmt(&mut self, s: &LabeledStmt) {
+        s.body.visit_with(self);
+    }
+
+    fn visit_break_stmt(&mut self, _: &BreakStmt) {}
+
+    fn visit_continue_stmt(&mut self, _: &ContinueStmt) {}
+
+    fn visit_fn_expr(&mut self, f: &FnExpr) {
+        self.visit_named(f.ident.as_ref(), &f.function);
+    }
+
+    fn visit_class_expr(&mut self, c: &ClassExpr) {
+        self.visit_named(c.ident.as_ref(), &c.class);
+    }
+
+    fn visit_named_export(&mut self, n: &NamedExport) {
+        // Re-exported names are not locals.
+        if n.src.is_none() {
+            n.visit_children_with(self);
+        }
     }
 }
 
-impl Repeated for RemoveExportsExprs {
-    fn changed(&self) -> bool {
-        self.state.should_run_again
+impl<C: Comments> RemoveExportsExprs<C> {
+    /// Returns the initializers of the declarators exported by `item` which
+    /// are kept if the declarators are removed, by the span of the latter.
+    fn kept_initializers(&self, item: &ModuleItem) -> Vec<(Span, Box<Expr>)> {
+        let ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl: Decl::Var(v), .. })) = item else {
+            return vec![];
+        };
+
+        v.decls
+            .iter()
+            .filter(|d| self.state.keeps_initializer(d))
+            .filter_map(|d| Some((d.span, d.init.clone()?)))
+            .collect()
     }
 
-    fn reset(&mut self) {
-        self.state.refs_from_other.clear();
-        self.state.cur_declaring.clear();
-        self.state.should_run_again = false;
+    /// Self-check: no binding dropped by this transform may still be used by
+    /// the surviving code, unless the output declares it again.
+    ///
+    /// Returns the names of such bindings.
+    fn dangling_refs(&self, m: &Module) -> Vec<String> {
+        let declared: AHashSet<Id> = collect_decls(m);
+        let mut used = IdentCollector::default();
+        m.visit_with(&mut used);
+
+        let mut dangling = used
+            .ids
+            .iter()
+            .filter(|id| self.state.removed.contains(id) && !declared.contains(id))
+            .map(|id| format!("`{}`", id.0))
+            .collect::<Vec<_>>();
+        dangling.sort();
+        dangling
     }
 }
 
-/// `VisitMut` is faster than [Fold], but we use [Fold] because it's much easier
-/// to read.
-///
-/// Note: We don't implement `fold_script` because next.js doesn't use it.
-impl Fold for RemoveExportsExprs {
+/// Scripts are transformed as modules without module declarations.
+impl<C: Comments> VisitMut for RemoveExportsExprs<C> {
     // This is important for reducing binary sizes.
-    noop_fold_type!();
+    noop_visit_mut_type!();
+
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        if !self.state.config.commonjs {
+            tracing::warn!(
+                "remove_export_exprs: scripts have no ES exports, enable `commonjs` to remove \
+                 CommonJS exports"
+            );
+        }
+
+        let mut m = Module {
+            span: s.span,
+            body: take(&mut s.body).into_iter().map(ModuleItem::Stmt).collect(),
+            shebang: s.shebang.take(),
+        };
+        self.visit_mut_module(&mut m);
+
+        *s = Script {
+            span: m.span,
+            body: m.body.into_iter().filter_map(|i| i.stmt()).collect(),
+            shebang: m.shebang,
+        };
+    }
 
     // Remove import expression
-    fn fold_import_decl(&mut self, mut i: ImportDecl) -> ImportDecl {
+    fn visit_mut_import_decl(&mut self, i: &mut ImportDecl) {
         // Imports for side effects.
         if i.specifiers.is_empty() {
-            return i;
+            return;
         }
 
         i.specifiers.retain(|s| match s {
@@ -370,259 +3160,879 @@ impl Fold for RemoveExportsExprs {
                         local.span
                     );
 
-                    self.state.should_run_again = true;
+                    self.state.removed.insert(local.to_id());
                     false
                 } else {
                     true
                 }
             }
         });
-
-        i
     }
 
-    fn fold_module(&mut self, mut m: Module) -> Module {
+    fn visit_mut_module(&mut self, m: &mut Module) {
         tracing::info!("remove_export_exprs: Start");
+        let Some(kept_names) = file_directives(m, &self.comments) else {
+            tracing::debug!("remove_export_exprs: disabled by {}", DISABLE_COMMENT);
+            return;
+        };
+        self.state.kept_names = kept_names;
+        if !self.state.config.tags.is_empty() {
+            self.state.marked = tagged_exports(m, &self.comments, &self.state.config.tags);
+        }
+        if !self.state.config.factories.is_empty() {
+            self.state.marked.extend(factory_exports(m, &self.state.config.factories));
+        }
+
+        if self.state.config.warn_phantom_exports {
+            warn_phantom_exports(m);
+        }
+        if self.state.config.warn_unmatched_exports {
+            warn_unmatched_exports(m, &self.state.config);
+        }
+        if self.state.config.max_passes.is_some() {
+            warn("remove-export: `maxPasses` is deprecated and ignored, unused code is removed in a single pass");
+        }
+
+        // Most modules have none of the matched exports.
+        self.state.default_local = default_local(m);
+        if self.state.matches_nothing(m) {
+            tracing::debug!("remove_export_exprs: no matched export");
+            self.state.reset_for_new_module();
+            return;
+        }
+        let original = self.state.config.strict.then(|| m.clone());
+        let exported = self.state.stubs_exports().then(|| value_exports(&m.body));
+        // `export =` is compiled to CommonJS.
+        let was_esm = m.body.iter().any(ModuleItem::is_module_decl)
+            && !m
+                .body
+                .iter()
+                .any(|i| matches!(i, ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(..))));
+
+        // Matched exports are already removed by the analyzer.
+        let declared = (self.state.config.annotate_removals && !self.state.config.dry_run).then(|| {
+            m.body
+                .iter()
+                .map(|i| (i.span().lo, Manifest::of(std::slice::from_ref(i))))
+                .collect::<Vec<_>>()
+        });
+
+        self.state.bindings = top_level_bindings(m);
+        self.state.imported = imported_bindings(m);
+        self.state.default_decl = default_decl(m);
+        self.state.ignored = ignored_bindings(m, &self.comments);
+        self.state.type_only = type_only_decls(m);
+        self.state.find_kept_exports(m);
+
+        if self.state.config.unexport {
+            self.state.split_unexported_decls(m);
+        }
+
         {
             // Fill the state.
+            let comments = removal_comments(&self.comments, &self.state.config);
             let mut v = Analyzer {
                 state: &mut self.state,
                 in_lhs_of_var: false,
                 in_data_fn: false,
+                declaring: vec![],
+                in_partial_export: false,
+                comments,
             };
-            m = m.fold_with(&mut v);
+            m.visit_mut_with(&mut v);
+        }
+
+        // Types of top-level declarations are recorded by the analyzer.
+        let type_refs = m
+            .body
+            .iter()
+            .filter(|i| {
+                !matches!(
+                    i,
+                    ModuleItem::ModuleDecl(ModuleDecl::Import(..)) | ModuleItem::Stmt(Stmt::Decl(..))
+                )
+            })
+            .flat_map(|i| match i {
+                // Removed declarators are dropped by the transform.
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl: Decl::Var(v), .. })) => v
+                    .decls
+                    .iter()
+                    .filter(|d| !self.state.should_remove_declarator(d))
+                    .flat_map(type_refs)
+                    .collect(),
+                _ => type_refs(i),
+            })
+            .collect::<Vec<_>>();
+        self.state.type_refs.extend(type_refs);
+        self.state.resolve_deps();
+
+        // Removes everything only reachable from the data functions at once.
+        m.visit_mut_children_with(self);
+
+        // The declarations not replaced in place, like destructured ones or
+        // specifiers.
+        if let Some(exported) = exported {
+            let removed = difference(exported, &value_exports(&m.body));
+            let init = self.state.stub.clone().unwrap_or_else(undefined);
+            add_export_stubs(m, removed, init);
+        }
+
+        if !self.state.removed.is_empty() {
+            m.visit_mut_with(&mut WidenTypeQueries {
+                removed: &self.state.removed,
+                declared: collect_decls(&*m),
+            });
+        }
+
+        if was_esm
+            && self.state.config.preserve_esm.unwrap_or(true)
+            && !m.body.iter().any(ModuleItem::is_module_decl)
+        {
+            m.body.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                span: DUMMY_SP,
+                specifiers: vec![],
+                src: None,
+                type_only: false,
+                with: None,
+            })));
         }
 
-        m.fold_children_with(self)
+        if cfg!(debug_assertions) || self.state.config.strict {
+            let dangling = self.dangling_refs(m);
+
+            if !dangling.is_empty() {
+                let msg = format!(
+                    "remove-export: removed bindings are still referenced: {}",
+                    dangling.join(", ")
+                );
+
+                // Left untouched rather than broken.
+                if let Some(original) = original {
+                    error(&msg);
+                    *m = original;
+                    self.put_back_comments();
+                    self.state.reset_for_new_module();
+                    return;
+                }
+
+                warn(&msg);
+            }
+        }
+
+        self.restore_comments(m);
+        if let Some(declared) = declared {
+            self.add_tombstones(m, declared);
+        }
+
+        self.state.reset_for_new_module();
     }
 
-    fn fold_module_items(&mut self, mut items: Vec<ModuleItem>) -> Vec<ModuleItem> {
-        items = items.fold_children_with(self);
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        for mut item in take(items) {
+            let inits = self.kept_initializers(&item);
+            item.visit_mut_with(self);
+
+            // Kept after the declaration if it is removed.
+            let kept = match &item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Var(v), ..
+                })) => v.decls.iter().map(|d| d.span).collect(),
+                _ => vec![],
+            };
+            items.push(item);
+            items.extend(
+                inits
+                    .into_iter()
+                    .filter(|(span, _)| !kept.contains(span))
+                    .map(|(span, expr)| ModuleItem::Stmt(Stmt::Expr(ExprStmt { span, expr }))),
+            );
+        }
 
         // Drop nodes.
         items.retain(|s| !matches!(s, ModuleItem::Stmt(Stmt::Empty(..))));
-
-        items
     }
 
-    fn fold_module_item(&mut self, i: ModuleItem) -> ModuleItem {
-        if let ModuleItem::ModuleDecl(ModuleDecl::Import(i)) = i {
-            let is_for_side_effect = i.specifiers.is_empty();
-            let i = i.fold_with(self);
+    fn visit_mut_module_item(&mut self, i: &mut ModuleItem) {
+        if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = i {
+            let span = import.span;
+            let is_for_side_effect = import.specifiers.is_empty();
+
+            // Only dropped if something was removed from the module, and never
+            // with attributes, like `with { type: 'css' }`, which are loaded
+            // for what they are rather than for their side effects.
+            if is_for_side_effect
+                && import.with.is_none()
+                && !self.state.refs_from_data_fn.is_empty()
+                && self.state.config.drops_side_effect_import(&import.src.value)
+            {
+                self.drop_comments(span);
+                *i = ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span }));
+                return;
+            }
+
+            import.visit_mut_with(self);
 
-            if !is_for_side_effect && i.specifiers.is_empty() {
-                return ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span: DUMMY_SP }));
+            if !is_for_side_effect && import.specifiers.is_empty() {
+                self.drop_comments(span);
+                *i = ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span }));
             }
 
-            return ModuleItem::ModuleDecl(ModuleDecl::Import(i));
+            return;
+        }
+
+        if let ModuleItem::ModuleDecl(ModuleDecl::ExportAll(e)) = i {
+            if self.state.config.export_all == ExportAll::Remove
+                && self.state.config.removes_from(&e.src.value)
+            {
+                let span = e.span;
+                self.drop_comments(span);
+                *i = ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span }));
+                return;
+            }
         }
 
-        let i = i.fold_children_with(self);
+        if let ModuleItem::ModuleDecl(
+            ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { span, .. })
+            | ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { span, .. }),
+        ) = i
+        {
+            if self.state.should_remove_default() {
+                let span = *span;
+                let expr = match &self.state.config.default_replacement {
+                    // Handled by `visit_mut_default_decl` and below.
+                    DefaultReplacement::EmptyFunction => None,
+                    DefaultReplacement::Null => Some(Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP })))),
+                    DefaultReplacement::Undefined => Some(undefined()),
+                    // Falls back to an empty function if invalid.
+                    DefaultReplacement::Expr(..) => self.state.default_expr.clone(),
+                    DefaultReplacement::None => {
+                        self.drop_comments(span);
+                        *i = ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span }));
+                        return;
+                    }
+                };
+
+                if let Some(expr) = expr {
+                    *i = ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                        span,
+                        expr,
+                    }));
+                    return;
+                }
 
-        match &i {
-            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(e)) if e.specifiers.is_empty() => {
-                return ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span: DUMMY_SP }))
+                // `export default function() {}` is a declaration, so that running the
+                // transform again on its output leaves it as is.
+                if let ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(e)) = &*i {
+                    let f = self.create_empty_fn(e.expr.span(), self.state.default_decl);
+                    *i = ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                        span,
+                        decl: DefaultDecl::Fn(f),
+                    }));
+                    return;
+                }
             }
-            _ => {}
         }
 
-        i
+        i.visit_mut_children_with(self);
+
+        let span = match i {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(e)) if e.specifiers.is_empty() => e.span,
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                span,
+                decl: Decl::Var(v),
+            })) if v.decls.is_empty() => *span,
+            _ => return,
+        };
+        self.drop_comments(span);
+        *i = ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span }));
     }
 
-    fn fold_named_export(&mut self, mut n: NamedExport) -> NamedExport {
-        n.specifiers = n.specifiers.fold_with(self);
+    fn visit_mut_named_export(&mut self, n: &mut NamedExport) {
+        n.specifiers.visit_mut_with(self);
 
+        if !self.state.acts_on(ExportForm::Named) {
+            return;
+        }
+        if let Some(src) = &n.src {
+            if !self.state.config.removes_from(&src.value) {
+                return;
+            }
+        }
+
+        let is_reexport = n.src.is_some();
         n.specifiers.retain(|s| {
-            let preserve = match s {
-                ExportSpecifier::Namespace(ExportNamespaceSpecifier {
-                    name: ModuleExportName::Ident(exported),
-                    ..
-                })
-                | ExportSpecifier::Default(ExportDefaultSpecifier { exported, .. })
-                | ExportSpecifier::Named(ExportNamedSpecifier {
-                    exported: Some(ModuleExportName::Ident(exported)),
-                    ..
-                }) => self
-                    .state
-                    .should_remove_identifier(exported)
-                    .map(|should_remove_identifier| !should_remove_identifier),
-                ExportSpecifier::Named(ExportNamedSpecifier {
-                    orig: ModuleExportName::Ident(orig),
-                    ..
-                }) => self
-                    .state
-                    .should_remove_identifier(orig)
-                    .map(|should_remove_identifier| !should_remove_identifier),
-                _ => Ok(true),
+            let (name, orig) = match s {
+                ExportSpecifier::Named(ExportNamedSpecifier { orig, exported, .. }) => {
+                    let local = match orig {
+                        ModuleExportName::Ident(orig) => Some(orig),
+                        ModuleExportName::Str(..) => None,
+                    };
+                    (exported.as_ref().unwrap_or(orig).atom(), local)
+                }
+                ExportSpecifier::Namespace(ExportNamespaceSpecifier { name, .. }) => (name.atom(), None),
+                ExportSpecifier::Default(ExportDefaultSpecifier { exported }) => (&exported.sym, None),
             };
 
-            match preserve {
-                Ok(false) => {
-                    tracing::trace!("Dropping a export specifier because it's a data identifier");
+            // Re-exported names are not locals.
+            let remove = match orig {
+                Some(orig) if !is_reexport => self.state.should_remove_specifier(name, orig),
+                _ => self.state.matches(name, DeclInfo::default()),
+            };
+            if !remove {
+                return true;
+            }
 
-                    if let ExportSpecifier::Named(ExportNamedSpecifier {
-                        orig: ModuleExportName::Ident(orig),
-                        ..
-                    }) = s
-                    {
-                        self.state.should_run_again = true;
-                        self.state.refs_from_data_fn.insert(orig.to_id());
-                    }
+            tracing::trace!("Dropping a export specifier because it's a data identifier");
 
-                    false
-                }
-                Ok(true) => true,
-                Err(_) => false,
-            }
+            false
         });
-
-        n
     }
 
-    fn fold_default_decl(&mut self, d: DefaultDecl) -> DefaultDecl {
+    fn visit_mut_default_decl(&mut self, d: &mut DefaultDecl) {
         if self.state.should_remove_default() {
             // Replace with an empty function
-            return DefaultDecl::Fn(self.create_empty_fn())
+            *d = DefaultDecl::Fn(self.create_empty_fn(d.span(), self.state.default_decl));
         }
-        d
     }
 
-    fn fold_export_default_expr(&mut self, n: ExportDefaultExpr) -> ExportDefaultExpr {
-        if self.state.should_remove_default() {
-            // Replace with an empty function
-            return ExportDefaultExpr {
-                span: DUMMY_SP,
-                expr: Box::new(Expr::Fn(self.create_empty_fn()))
-            };
-        }
-        n
-    }
+    /// This methods sets the pattern to [Pat::Invalid] if it should be
+    /// removed.
+    fn visit_mut_pat(&mut self, p: &mut Pat) {
+        p.visit_mut_children_with(self);
 
-    /// This methods returns [Pat::Invalid] if the pattern should be removed.
-    fn fold_pat(&mut self, mut p: Pat) -> Pat {
-        p = p.fold_children_with(self);
+        if !self.in_lhs_of_var {
+            return;
+        }
 
-        if self.in_lhs_of_var {
-            match &mut p {
-                Pat::Ident(name) => {
-                    if self.should_remove(name.id.to_id()) {
-                        self.state.should_run_again = true;
-                        tracing::trace!(
-                            "Dropping var `{}{:?}` because it should be removed",
-                            name.id.sym,
-                            name.id.span
-                        );
+        match p {
+            Pat::Ident(name) => {
+                if self.should_remove(name.id.to_id()) {
+                    tracing::trace!(
+                        "Dropping var `{}{:?}` because it should be removed",
+                        name.id.sym,
+                        name.id.span
+                    );
+                    self.state.removed.insert(name.id.to_id());
 
-                        return Pat::Invalid(Invalid { span: DUMMY_SP });
-                    }
+                    *p = Pat::Invalid(Invalid { span: DUMMY_SP });
                 }
-                Pat::Array(arr) => {
-                    if !arr.elems.is_empty() {
-                        arr.elems.retain(|e| !matches!(e, Some(Pat::Invalid(..))));
+            }
+            Pat::Array(arr) => {
+                if !arr.elems.is_empty() {
+                    // Removed elements become holes, so the remaining ones keep
+                    // their positions.
+                    let mut removed = false;
+                    for elem in arr.elems.iter_mut() {
+                        if matches!(elem, Some(Pat::Invalid(..))) {
+                            *elem = None;
+                            removed = true;
+                        }
+                    }
+
+                    if removed {
+                        // Trailing holes are meaningless, so `[a, , ]` becomes `[a]`.
+                        while matches!(arr.elems.last(), Some(None)) {
+                            arr.elems.pop();
+                        }
 
-                        if arr.elems.is_empty() {
-                            return Pat::Invalid(Invalid { span: DUMMY_SP });
+                        if arr.elems.iter().all(|e| e.is_none()) {
+                            *p = Pat::Invalid(Invalid { span: DUMMY_SP });
                         }
                     }
                 }
-                Pat::Object(obj) => {
-                    if !obj.props.is_empty() {
-                        obj.props = take(&mut obj.props)
-                            .into_iter()
-                            .filter_map(|prop| match prop {
-                                ObjectPatProp::KeyValue(prop) => {
-                                    if prop.value.is_invalid() {
-                                        None
-                                    } else {
-                                        Some(ObjectPatProp::KeyValue(prop))
-                                    }
+            }
+            Pat::Object(obj) => {
+                if !obj.props.is_empty() {
+                    obj.props = take(&mut obj.props)
+                        .into_iter()
+                        .filter_map(|prop| match prop {
+                            ObjectPatProp::KeyValue(prop) => {
+                                if prop.value.is_invalid() {
+                                    None
+                                } else {
+                                    Some(ObjectPatProp::KeyValue(prop))
                                 }
-                                ObjectPatProp::Assign(prop) => {
-                                    if self.should_remove(prop.key.to_id()) {
-                                        self.mark_as_candidate(prop.value);
-
-                                        None
-                                    } else {
-                                        Some(ObjectPatProp::Assign(prop))
-                                    }
+                            }
+                            ObjectPatProp::Assign(prop) => {
+                                if self.should_remove(prop.key.to_id()) {
+                                    None
+                                } else {
+                                    Some(ObjectPatProp::Assign(prop))
                                 }
-                                ObjectPatProp::Rest(prop) => {
-                                    if prop.arg.is_invalid() {
-                                        None
-                                    } else {
-                                        Some(ObjectPatProp::Rest(prop))
-                                    }
+                            }
+                            ObjectPatProp::Rest(prop) => {
+                                if prop.arg.is_invalid() {
+                                    None
+                                } else {
+                                    Some(ObjectPatProp::Rest(prop))
                                 }
-                            })
-                            .collect();
+                            }
+                        })
+                        .collect();
 
-                        if obj.props.is_empty() {
-                            return Pat::Invalid(Invalid { span: DUMMY_SP });
-                        }
+                    if obj.props.is_empty() {
+                        *p = Pat::Invalid(Invalid { span: DUMMY_SP });
                     }
                 }
-                Pat::Rest(rest) => {
-                    if rest.arg.is_invalid() {
-                        return Pat::Invalid(Invalid { span: DUMMY_SP });
-                    }
+            }
+            Pat::Rest(rest) => {
+                if rest.arg.is_invalid() {
+                    *p = Pat::Invalid(Invalid { span: DUMMY_SP });
                 }
-                _ => {}
             }
-        }
-
-        p
-    }
-
-    #[allow(clippy::single_match)]
-    fn fold_stmt(&mut self, mut s: Stmt) -> Stmt {
-        match s {
-            Stmt::Decl(Decl::Fn(f)) => {
-                if self.should_remove(f.ident.to_id()) {
-                    self.mark_as_candidate(f.function);
-                    return Stmt::Empty(EmptyStmt { span: DUMMY_SP });
+            // `b = dflt()`, dropped along with its default value.
+            Pat::Assign(assign) => {
+                if assign.left.is_invalid() {
+                    *p = Pat::Invalid(Invalid { span: DUMMY_SP });
                 }
-
-                s = Stmt::Decl(Decl::Fn(f));
             }
             _ => {}
         }
+    }
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        let span = s.span();
 
-        let s = s.fold_children_with(self);
         match s {
-            Stmt::Decl(Decl::Var(v)) if v.decls.is_empty() => {
-                return Stmt::Empty(EmptyStmt { span: DUMMY_SP });
+            Stmt::Decl(Decl::Fn(f)) if self.should_remove(f.ident.to_id()) => {
+                self.state.removed.insert(f.ident.to_id());
+                self.drop_comments(f.function.span);
+                *s = Stmt::Empty(EmptyStmt { span });
+                return;
+            }
+            Stmt::Decl(Decl::Class(c)) if self.should_remove(c.ident.to_id()) => {
+                self.state.removed.insert(c.ident.to_id());
+                self.drop_comments(c.class.span);
+                *s = Stmt::Empty(EmptyStmt { span });
+                return;
+            }
+            Stmt::Decl(Decl::TsEnum(e)) if self.should_remove(e.id.to_id()) => {
+                self.state.removed.insert(e.id.to_id());
+                self.drop_comments(e.span);
+                *s = Stmt::Empty(EmptyStmt { span });
+                return;
+            }
+            Stmt::Decl(Decl::TsModule(m))
+                if namespace_id(m).is_some_and(|id| self.should_remove(id.to_id())) =>
+            {
+                self.state.removed.insert(namespace_id(m).unwrap().to_id());
+                self.drop_comments(m.span);
+                *s = Stmt::Empty(EmptyStmt { span });
+                return;
             }
             _ => {}
         }
 
-        s
+        s.visit_mut_children_with(self);
+
+        if let Stmt::Decl(Decl::Var(v)) = s {
+            if v.decls.is_empty() {
+                let span = v.span;
+                self.drop_comments(span);
+                *s = Stmt::Empty(EmptyStmt { span });
+            }
+        }
     }
 
     /// This method make `name` of [VarDeclarator] to [Pat::Invalid] if it
     /// should be removed.
-    fn fold_var_declarator(&mut self, mut d: VarDeclarator) -> VarDeclarator {
+    fn visit_mut_var_declarator(&mut self, d: &mut VarDeclarator) {
         let old = self.in_lhs_of_var;
         self.in_lhs_of_var = true;
-        let name = d.name.fold_with(self);
+        d.name.visit_mut_with(self);
 
         self.in_lhs_of_var = false;
-        if name.is_invalid() {
-            d.init = self.mark_as_candidate(d.init);
-        }
-        let init = d.init.fold_with(self);
+        d.init.visit_mut_with(self);
         self.in_lhs_of_var = old;
+    }
+
+    /// Nested scopes are kept as is, see [Self::should_remove].
+    fn visit_mut_block_stmt(&mut self, _: &mut BlockStmt) {}
+
+    fn visit_mut_decorators(&mut self, decorators: &mut Vec<Decorator>) {
+        decorators.retain(|d| !self.state.uses_removed_decls(d));
+        decorators.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_var_declarators(&mut self, decls: &mut Vec<VarDeclarator>) {
+        decls.visit_mut_children_with(self);
+        decls.retain(|d| {
+            if d.name.is_invalid() {
+                self.drop_comments(d.span);
+                return false;
+            }
+
+            true
+        });
+    }
+}
+
+/// Runs `pass` on a copy of the program to report the nodes it removes, with
+/// diagnostics, a manifest or, for dry runs, comments annotating them.
+struct Report<V, C> {
+    pass: V,
+    comments: Option<C>,
+    dry_run: bool,
+    diagnostics: bool,
+    manifest: bool,
+    stats: bool,
+}
+
+impl<V: VisitMut, C: Comments> Report<V, C> {
+    fn run<N>(&mut self, original: &mut N, items: fn(&N) -> Vec<ModuleItem>)
+    where
+        N: Clone + Spanned + VisitMutWith<V> + VisitWith<RemovedNodes>,
+    {
+        let mut transformed = original.clone();
+        transformed.visit_mut_with(&mut self.pass);
+
+        let mut kept = RemovedNodes::default();
+        transformed.visit_with(&mut kept);
+
+        let mut v = RemovedNodes {
+            kept: Some(kept.nodes.into_iter().map(|(span, _)| span).collect()),
+            nodes: Default::default(),
+        };
+        original.visit_with(&mut v);
+
+        for (span, kind) in v.nodes {
+            if self.diagnostics && HANDLER.is_set() {
+                HANDLER.with(|handler| {
+                    handler.span_note_without_error(span, &format!("remove-export: removed {}", kind))
+                });
+            }
+
+            if self.dry_run {
+                match &self.comments {
+                    Some(comments) => comments.add_leading(
+                        span.lo,
+                        Comment {
+                            kind: CommentKind::Block,
+                            span: DUMMY_SP,
+                            text: " swc-remove-export: removed ".into(),
+                        },
+                    ),
+                    None => tracing::info!("remove_export_exprs: would remove {} {:?}", kind, span),
+                }
+            }
+        }
+
+        if self.stats {
+            Manifest::new(&items(original), &items(&transformed)).report_stats();
+        }
+
+        if self.manifest {
+            let manifest = Manifest::new(&items(original), &items(&transformed));
+            let json = serde_json::to_string(&manifest).unwrap();
+
+            match &self.comments {
+                Some(comments) => comments.add_trailing(
+                    original.span().hi,
+                    Comment {
+                        kind: CommentKind::Block,
+                        span: DUMMY_SP,
+                        text: format!(" swc-remove-export-manifest {} ", json).into(),
+                    },
+                ),
+                None => tracing::info!("remove_export_exprs: manifest {}", json),
+            }
+        }
+
+        if !self.dry_run {
+            *original = transformed;
+        }
+    }
+}
+
+impl<V: VisitMut, C: Comments> VisitMut for Report<V, C> {
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        self.run(m, |m| m.body.clone())
+    }
+
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        self.run(s, |s| s.body.iter().cloned().map(ModuleItem::Stmt).collect())
+    }
+}
+
+/// What [Config::manifest] lists as removed.
+#[derive(Debug, Default, Serialize)]
+struct Manifest {
+    exports: Vec<Atom>,
+    /// Local names of the removed imports by source, empty for side-effect
+    /// imports.
+    imports: BTreeMap<Atom, Vec<Atom>>,
+    declarations: Vec<Atom>,
+}
+
+impl Manifest {
+    fn new(original: &[ModuleItem], transformed: &[ModuleItem]) -> Self {
+        let original = Manifest::of(original);
+        let transformed = Manifest::of(transformed);
+
+        let mut imports = original.imports;
+        for (src, locals) in &mut imports {
+            let kept = transformed.imports.get(src);
+            locals.retain(|l| !kept.is_some_and(|kept| kept.contains(l)));
+        }
+        imports.retain(|src, locals| !locals.is_empty() || !transformed.imports.contains_key(src));
+
+        Manifest {
+            exports: difference(original.exports, &transformed.exports),
+            imports,
+            declarations: difference(original.declarations, &transformed.declarations),
+        }
+    }
+
+    /// Logs the numbers of removed exports, imports and declarations, and
+    /// notes them through the diagnostics handler of swc.
+    fn report_stats(&self) {
+        // Side-effect imports have no local.
+        let imports = self.imports.values().map(|locals| locals.len().max(1)).sum::<usize>();
+        let (exports, declarations) = (self.exports.len(), self.declarations.len());
+
+        tracing::info!(exports, imports, declarations, "remove_export_exprs: stats");
+        if HANDLER.is_set() {
+            HANDLER.with(|handler| {
+                handler.note_without_error(&format!(
+                    "remove-export: removed {} exports, {} imports, {} declarations",
+                    exports, imports, declarations
+                ))
+            });
+        }
+    }
+
+    /// Lists everything declared by `items`.
+    fn of(items: &[ModuleItem]) -> Self {
+        let mut m = Manifest::default();
+
+        for item in items {
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(i)) => {
+                    m.imports
+                        .entry(i.src.value.clone())
+                        .or_default()
+                        .extend(i.specifiers.iter().map(|s| match s {
+                            ImportSpecifier::Named(ImportNamedSpecifier { local, .. })
+                            | ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                            | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                                local.sym.clone()
+                            }
+                        }));
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(e)) => m.exports.extend(decl_names(&e.decl)),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(e)) => {
+                    m.exports.extend(e.specifiers.iter().map(|s| match s {
+                        ExportSpecifier::Named(ExportNamedSpecifier { orig, exported, .. }) => {
+                            exported.as_ref().unwrap_or(orig).atom().clone()
+                        }
+                        ExportSpecifier::Namespace(ExportNamespaceSpecifier { name, .. }) => {
+                            name.atom().clone()
+                        }
+                        ExportSpecifier::Default(ExportDefaultSpecifier { exported }) => {
+                            exported.sym.clone()
+                        }
+                    }));
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(..) | ModuleDecl::ExportDefaultExpr(..)) => {
+                    m.exports.push("default".into())
+                }
+                ModuleItem::Stmt(Stmt::Decl(d)) => m.declarations.extend(decl_names(d)),
+                _ => {}
+            }
+        }
+
+        m
+    }
+}
+
+/// Returns the binding of the function, class or namespace declared by `d`.
+fn decl_ids(d: &Decl) -> Vec<Id> {
+    match d {
+        Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }) => vec![ident.to_id()],
+        Decl::TsModule(m) => namespace_id(m).map(|id| id.to_id()).into_iter().collect(),
+        Decl::TsEnum(e) => vec![e.id.to_id()],
+        _ => vec![],
+    }
+}
+
+/// Returns the names declared by `d`.
+fn decl_names(d: &Decl) -> Vec<Atom> {
+    match d {
+        Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }) => vec![ident.sym.clone()],
+        Decl::Var(v) => find_pat_ids::<_, Ident>(&v.decls).into_iter().map(|id| id.sym).collect(),
+        _ => vec![],
+    }
+}
+
+/// Returns the names of the values exported by `items`, except `default`.
+fn value_exports(items: &[ModuleItem]) -> Vec<Atom> {
+    let mut names = vec![];
+    for item in items {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(e)) => names.extend(decl_names(&e.decl)),
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(e)) if !e.type_only => {
+                names.extend(e.specifiers.iter().filter_map(|s| match s {
+                    ExportSpecifier::Named(ExportNamedSpecifier {
+                        orig,
+                        exported,
+                        is_type_only: false,
+                        ..
+                    }) => Some(exported.as_ref().unwrap_or(orig).atom().clone()),
+                    ExportSpecifier::Namespace(ExportNamespaceSpecifier { name, .. }) => Some(name.atom().clone()),
+                    _ => None,
+                }));
+            }
+            _ => {}
+        }
+    }
+    names.retain(|name| name != "default");
+    names
+}
+
+/// Appends `export const name = init` to `m` for each of the removed exports
+/// `names`, or `const _name = init; export { _name as name }` if `name` is
+/// still declared or isn't an identifier.
+fn add_export_stubs(m: &mut Module, names: Vec<Atom>, init: Box<Expr>) {
+    let declared: AHashSet<Atom> = collect_decls::<Id, _>(&*m).into_iter().map(|id| id.0).collect();
+    let mut taken = declared.clone();
+
+    for name in names {
+        if !declared.contains(&name) && Ident::verify_symbol(&name).is_ok() {
+            m.body.push(export_stub(DUMMY_SP, Ident::new_no_ctxt(name.clone(), DUMMY_SP), init.clone()));
+            continue;
+        }
+
+        let base = if Ident::verify_symbol(&name).is_ok() {
+            format!("_{}", name)
+        } else {
+            "_stub".to_string()
+        };
+        let mut local = base.clone();
+        let mut n = 1;
+        while taken.contains(&Atom::from(&*local)) {
+            n += 1;
+            local = format!("{}{}", base, n);
+        }
+        let local: Atom = local.into();
+        taken.insert(local.clone());
 
-        VarDeclarator { name, init, ..d }
+        let ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) =
+            export_stub(DUMMY_SP, Ident::new_no_ctxt(local.clone(), DUMMY_SP), init.clone())
+        else {
+            unreachable!()
+        };
+        m.body.push(ModuleItem::Stmt(Stmt::Decl(decl)));
+        m.body.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+            span: DUMMY_SP,
+            specifiers: vec![ExportSpecifier::Named(ExportNamedSpecifier {
+                span: DUMMY_SP,
+                orig: ModuleExportName::Ident(Ident::new_no_ctxt(local, DUMMY_SP)),
+                exported: Some(if Ident::verify_symbol(&name).is_ok() {
+                    ModuleExportName::Ident(Ident::new_no_ctxt(name, DUMMY_SP))
+                } else {
+                    ModuleExportName::Str(name.into())
+                }),
+                is_type_only: false,
+            })],
+            src: None,
+            type_only: false,
+            with: None,
+        })));
     }
+}
+
+/// Returns the elements of `a` which are not in `b`.
+fn difference(mut a: Vec<Atom>, b: &[Atom]) -> Vec<Atom> {
+    a.retain(|x| !b.contains(x));
+    a
+}
+
+/// Collects the spans of module items, statements, specifiers and
+/// declarators, along with what they are.
+///
+/// With `kept` set, only collects the outermost ones missing from `kept`.
+#[derive(Default)]
+struct RemovedNodes {
+    kept: Option<FxHashSet<Span>>,
+    nodes: Vec<(Span, &'static str)>,
+}
 
-    fn fold_var_declarators(&mut self, mut decls: Vec<VarDeclarator>) -> Vec<VarDeclarator> {
-        decls = decls.fold_children_with(self);
-        decls.retain(|d| !d.name.is_invalid());
+impl RemovedNodes {
+    /// Records the node at `span`, returning whether its children should be
+    /// visited.
+    fn record(&mut self, span: Span, kind: &'static str) -> bool {
+        match &self.kept {
+            Some(kept) if !kept.contains(&span) => {
+                self.nodes.push((span, kind));
+                false
+            }
+            Some(..) => true,
+            None => {
+                self.nodes.push((span, kind));
+                true
+            }
+        }
+    }
+}
 
-        decls
+fn stmt_kind(s: &Stmt) -> &'static str {
+    match s {
+        Stmt::Decl(..) => "declaration",
+        _ => "statement",
     }
 }
 
+impl Visit for RemovedNodes {
+    noop_visit_type!();
+
+    fn visit_module_item(&mut self, n: &ModuleItem) {
+        let kind = match n {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(..)) => "import",
+            ModuleItem::ModuleDecl(..) => "export",
+            ModuleItem::Stmt(s) => stmt_kind(s),
+        };
+
+        if self.record(n.span(), kind) {
+            n.visit_children_with(self);
+        }
+    }
+
+    fn visit_stmt(&mut self, n: &Stmt) {
+        // Removed statements may be left empty, with their original span.
+        if self.kept.is_none() && n.is_empty() {
+            return;
+        }
+
+        if self.record(n.span(), stmt_kind(n)) {
+            n.visit_children_with(self);
+        }
+    }
+
+    fn visit_import_specifier(&mut self, n: &ImportSpecifier) {
+        if self.record(n.span(), "import") {
+            n.visit_children_with(self);
+        }
+    }
+
+    fn visit_export_specifier(&mut self, n: &ExportSpecifier) {
+        if self.record(n.span(), "export") {
+            n.visit_children_with(self);
+        }
+    }
+
+    fn visit_var_declarator(&mut self, n: &VarDeclarator) {
+        if self.record(n.span(), "declaration") {
+            n.visit_children_with(self);
+        }
+    }
+
+    // Properties of `module.exports = { ... }`.
+    fn visit_prop_or_spread(&mut self, n: &PropOrSpread) {
+        if self.record(n.span(), "export") {
+            n.visit_children_with(self);
+        }
+    }
+}
 
 /// An example plugin function with macro support.
 /// `plugin_transform` macro interop pointers into deserialized structs, as well
@@ -643,14 +4053,30 @@ impl Fold for RemoveExportsExprs {
 /// if plugin need to handle low-level ptr directly. However, there are
 /// important steps manually need to be performed like sending transformed
 /// results back to host. Refer swc_plugin_macro how does it work internally.
+#[cfg(feature = "plugin")]
 #[plugin_transform]
 pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
-    let tr = serde_json::from_str::<Vec<String>>(
-        &_metadata
-            .get_transform_plugin_config()
-            .expect("failed to get plugin config for remove-export"),
-    )
-    .expect("invalid config for remove-export");
-
-    program.fold_with(&mut remove_export_exprs(tr))
+    let Some(json) = _metadata.get_transform_plugin_config() else {
+        error("remove-export: missing config");
+        return program;
+    };
+    // Reported rather than panicking, which would abort the whole compilation.
+    let configs = match parse_configs(&json) {
+        Ok(configs) => configs,
+        Err(err) => {
+            error(&err);
+            return program;
+        }
+    };
+
+    let filename = _metadata.get_context(&TransformPluginMetadataContextKind::Filename);
+    let Some(mut config) = select_config(configs, filename.as_deref()) else {
+        return program;
+    };
+    if let Some(filename) = &filename {
+        config = config.for_file(filename);
+    }
+    let config = config.for_env(_metadata.get_context(&TransformPluginMetadataContextKind::Env).as_deref());
+
+    program.fold_with(&mut remove_export_exprs_with_comments(config, _metadata.comments))
 }
@@ -0,0 +1,156 @@
+use swc_plugin_remove_export::{
+  parse_config, parse_configs, select_config, Config, DefaultReplacement, ExportForm, Mode,
+  PatternKind, RemoveExportConfig,
+};
+
+fn parse(json: &str) -> Config {
+  serde_json::from_str::<RemoveExportConfig>(json).unwrap().into()
+}
+
+#[test]
+fn parse_exports_array() {
+  let config = parse(r#"["getData", "default"]"#);
+
+  assert_eq!(config.exports, ["getData", "default"]);
+  assert_eq!(config.mode, Mode::Remove);
+  assert_eq!(config.default_replacement, DefaultReplacement::EmptyFunction);
+}
+
+#[test]
+fn parse_config_object() {
+  let config = parse(
+    r#"{
+      "exports": ["getData"],
+      "mode": "keep",
+      "defaultReplacement": "null",
+      "include": ["**/pages/**"]
+    }"#,
+  );
+
+  assert_eq!(config.exports, ["getData"]);
+  assert_eq!(config.mode, Mode::Keep);
+  assert_eq!(config.default_replacement, DefaultReplacement::Null);
+  assert_eq!(config.include, ["**/pages/**"]);
+}
+
+#[test]
+fn parse_default_replacement_expression() {
+  let config = parse(r#"{ "defaultReplacement": "{ props: {} }" }"#);
+
+  assert_eq!(
+    config.default_replacement,
+    DefaultReplacement::Expr(String::from("{ props: {} }"))
+  );
+}
+
+#[test]
+fn include_filters_files() {
+  let config = parse(r#"{ "include": ["**/pages/**", "*.server.js"] }"#);
+
+  assert!(config.includes("/app/src/pages/index.js"));
+  assert!(config.includes("/app/src/data.server.js"));
+  assert!(!config.includes("/app/src/components/button.js"));
+  assert!(parse("[]").includes("/app/src/components/button.js"));
+}
+
+#[test]
+fn exclude_filters_included_files() {
+  let config = parse(r#"{ "include": ["**/pages/**"], "exclude": ["**/node_modules/**"] }"#);
+
+  assert!(config.includes("/app/src/pages/index.js"));
+  assert!(!config.includes("/app/node_modules/lib/pages/index.js"));
+  assert!(!parse(r#"{ "exclude": ["**/*.test.js"] }"#).includes("/app/src/pages/index.test.js"));
+}
+
+#[test]
+fn parse_config_reports_invalid_config() {
+  let err = parse_config(r#"{ "exports": ["getData"], "mode": "removee" }"#).unwrap_err();
+
+  assert!(err.contains("unknown variant `removee`"), "{}", err);
+  assert!(err.contains(r#""mode": "removee""#), "{}", err);
+  assert!(parse_config(r#"["getData", 1]"#).is_err());
+  assert_eq!(parse_config(r#"["getData"]"#).unwrap().exports, ["getData"]);
+}
+
+#[test]
+fn rules_apply_to_their_env() {
+  let config = parse(
+    r#"{
+      "exports": ["getData"],
+      "rules": [
+        { "exports": ["serverOnlyHelpers"], "env": "browser" },
+        { "exports": ["getConfig"] }
+      ]
+    }"#,
+  );
+
+  assert_eq!(
+    config.clone().for_env(Some("browser")).exports,
+    ["getData", "serverOnlyHelpers", "getConfig"]
+  );
+  assert_eq!(config.clone().for_env(Some("node")).exports, ["getData", "getConfig"]);
+  assert_eq!(config.for_env(None).exports, ["getData", "getConfig"]);
+}
+
+#[test]
+fn build_config() {
+  let config = Config::new(["getData"])
+    .mode(Mode::Keep)
+    .patterns(PatternKind::Regex, ["^get"])
+    .default_replacement(DefaultReplacement::Null)
+    .forms([ExportForm::Decl])
+    .include(["**/pages/**"])
+    .exclude(["**/node_modules/**"])
+    .rule(Some("browser"), ["serverOnlyHelpers"]);
+
+  assert_eq!(config.exports, ["getData"]);
+  assert_eq!(config.mode, Mode::Keep);
+  assert_eq!(config.kind, PatternKind::Regex);
+  assert_eq!(config.patterns, ["^get"]);
+  assert_eq!(config.default_replacement, DefaultReplacement::Null);
+  assert_eq!(config.forms, [ExportForm::Decl]);
+  assert!(config.includes("/app/src/pages/index.js"));
+  assert!(!config.includes("/app/node_modules/lib/pages/index.js"));
+  assert_eq!(config.for_env(Some("browser")).exports, ["getData", "serverOnlyHelpers"]);
+}
+
+#[test]
+fn select_config_by_filename() {
+  let configs = parse_configs(
+    r#"[
+      { "test": "pages/**/*.tsx", "exports": ["getServerSideProps"] },
+      { "test": "app/**", "exports": ["metadata"] }
+    ]"#,
+  )
+  .unwrap();
+
+  let select = |filename| select_config(configs.clone(), filename).map(|c| c.exports);
+  assert_eq!(select(Some("/repo/src/pages/blog/post.tsx")).unwrap(), ["getServerSideProps"]);
+  assert_eq!(select(Some("/repo/src/app/layout.tsx")).unwrap(), ["metadata"]);
+  assert_eq!(select(Some("/repo/src/components/button.tsx")), None);
+  assert_eq!(select(None), None);
+
+  let configs = parse_configs(r#"["getData"]"#).unwrap();
+  assert_eq!(select_config(configs, Some("/repo/index.js")).unwrap().exports, ["getData"]);
+  assert!(parse_configs(r#"[{ "exports": "getData" }]"#).is_err());
+}
+
+#[test]
+fn unused_exports_apply_to_their_file() {
+  let config = parse(
+    r#"{
+      "exports": ["getData"],
+      "unusedExports": { "src/utils.ts": ["legacyHelper"], "src/page.ts": ["unused"] }
+    }"#,
+  );
+
+  assert_eq!(
+    config.clone().for_file("/repo/src/utils.ts").exports,
+    ["getData", "legacyHelper"]
+  );
+  assert_eq!(
+    config.clone().for_file("/repo/lib/src/utils.ts").exports,
+    ["getData", "legacyHelper"]
+  );
+  assert_eq!(config.for_file("/repo/mysrc/utils.ts").exports, ["getData"]);
+}
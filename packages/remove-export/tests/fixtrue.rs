@@ -1,10 +1,41 @@
-use std::path::PathBuf;
+use std::{fs, path::PathBuf};
 use swc_core::{
-  ecma::parser::{EsConfig, Syntax},
+  ecma::parser::{EsSyntax, Syntax, TsSyntax},
   ecma::transforms::testing::{test_fixture, FixtureTestConfig},
 };
 use testing::fixture;
-use swc_plugin_remove_export::{remove_export_exprs};
+use swc_plugin_remove_export::{
+  remove_export_exprs, remove_export_exprs_by, remove_export_exprs_with_comments,
+  remove_export_exprs_with_config, Config, ExportKind, RemoveExportConfig,
+};
+
+fn config(json: &str) -> Config {
+  serde_json::from_str::<RemoveExportConfig>(json).unwrap().into()
+}
+
+#[fixture("tests/fixture/options/**/input.js")]
+fn fixture_options(input: PathBuf) {
+  let parent = input.parent().unwrap();
+  let output = parent.join("output.js");
+  let options = fs::read_to_string(parent.join("options.json")).unwrap();
+
+  test_fixture(
+    Syntax::Es(EsSyntax {
+      decorators: true,
+      jsx: true,
+      import_attributes: true,
+      ..Default::default()
+    }),
+    &|_t| {
+      remove_export_exprs_with_config(config(&options))
+    },
+    &input,
+    &output,
+    FixtureTestConfig {
+      ..Default::default()
+    },
+  );
+}
 
 #[fixture("tests/fixture/base/input.js")]
 fn fixture_base(input: PathBuf) {
@@ -30,7 +61,7 @@ fn fixture_preserve_config(input: PathBuf) {
   let output = parent.join("output.js");
 
   test_fixture(
-    Syntax::Es(EsConfig {
+    Syntax::Es(EsSyntax {
       decorators: true,
       jsx: true,
       ..Default::default()
@@ -52,7 +83,7 @@ fn fixture_preserve_config_and_config(input: PathBuf) {
   let output = parent.join("output.js");
 
   test_fixture(
-    Syntax::Es(EsConfig {
+    Syntax::Es(EsSyntax {
       decorators: true,
       jsx: true,
       ..Default::default()
@@ -74,7 +105,7 @@ fn fixture_preserve_data(input: PathBuf) {
   let output = parent.join("output.js");
 
   test_fixture(
-    Syntax::Es(EsConfig {
+    Syntax::Es(EsSyntax {
       decorators: true,
       jsx: true,
       ..Default::default()
@@ -96,7 +127,7 @@ fn fixture_preserve_default(input: PathBuf) {
   let output = parent.join("output.js");
 
   test_fixture(
-    Syntax::Es(EsConfig {
+    Syntax::Es(EsSyntax {
       decorators: true,
       jsx: true,
       ..Default::default()
@@ -118,7 +149,7 @@ fn fixture_remove_data(input: PathBuf) {
   let output = parent.join("output.js");
 
   test_fixture(
-    Syntax::Es(EsConfig {
+    Syntax::Es(EsSyntax {
       decorators: true,
       jsx: true,
       ..Default::default()
@@ -132,4 +163,252 @@ fn fixture_remove_data(input: PathBuf) {
       ..Default::default()
     },
   );
-}
\ No newline at end of file
+}
+#[fixture("tests/fixture/warnPhantomExports/**/input.js")]
+fn fixture_warn_phantom_exports(input: PathBuf) {
+  let parent = input.parent().unwrap();
+  let output = parent.join("output.js");
+
+  test_fixture(
+    Syntax::Es(EsSyntax {
+      decorators: true,
+      jsx: true,
+      ..Default::default()
+    }),
+    &|_t| {
+      remove_export_exprs_with_config(config(r#"{ "exports": ["getData"], "warnPhantomExports": true }"#))
+    },
+    &input,
+    &output,
+    FixtureTestConfig {
+      ..Default::default()
+    },
+  );
+}
+
+#[fixture("tests/fixture/predicate/**/input.js")]
+fn fixture_predicate(input: PathBuf) {
+  let parent = input.parent().unwrap();
+  let output = parent.join("output.js");
+
+  test_fixture(
+    Syntax::Es(EsSyntax {
+      decorators: true,
+      jsx: true,
+      ..Default::default()
+    }),
+    &|_t| {
+      remove_export_exprs_by(|e| e.kind == ExportKind::Fn && e.is_async)
+    },
+    &input,
+    &output,
+    FixtureTestConfig {
+      ..Default::default()
+    },
+  );
+}
+
+#[fixture("tests/fixture/predicateClass/**/input.js")]
+fn fixture_predicate_class(input: PathBuf) {
+  let parent = input.parent().unwrap();
+  let output = parent.join("output.js");
+
+  test_fixture(
+    Syntax::Es(EsSyntax {
+      decorators: true,
+      jsx: true,
+      ..Default::default()
+    }),
+    &|_t| {
+      remove_export_exprs_by(|e| e.kind == ExportKind::Class)
+    },
+    &input,
+    &output,
+    FixtureTestConfig {
+      ..Default::default()
+    },
+  );
+}
+
+#[fixture("tests/fixture/typescript/**/input.ts")]
+fn fixture_typescript(input: PathBuf) {
+  let parent = input.parent().unwrap();
+  let output = parent.join("output.ts");
+  let options = fs::read_to_string(parent.join("options.json")).unwrap();
+
+  test_fixture(
+    Syntax::Typescript(TsSyntax {
+      decorators: true,
+      tsx: true,
+      ..Default::default()
+    }),
+    &|_t| {
+      remove_export_exprs_with_config(config(&options))
+    },
+    &input,
+    &output,
+    FixtureTestConfig {
+      ..Default::default()
+    },
+  );
+}
+
+#[fixture("tests/fixture/comments/**/input.js")]
+fn fixture_comments(input: PathBuf) {
+  let parent = input.parent().unwrap();
+  let output = parent.join("output.js");
+  let options = fs::read_to_string(parent.join("options.json")).unwrap();
+
+  test_fixture(
+    Syntax::Es(EsSyntax {
+      decorators: true,
+      jsx: true,
+      ..Default::default()
+    }),
+    &|t| {
+      remove_export_exprs_with_comments(
+        config(&options),
+        Some(t.comments.clone()),
+      )
+    },
+    &input,
+    &output,
+    FixtureTestConfig {
+      ..Default::default()
+    },
+  );
+}
+
+#[fixture("tests/fixture/commonjs/**/input.js")]
+fn fixture_commonjs(input: PathBuf) {
+  let parent = input.parent().unwrap();
+  let output = parent.join("output.js");
+  let options = fs::read_to_string(parent.join("options.json")).unwrap();
+
+  test_fixture(
+    Syntax::Es(EsSyntax {
+      decorators: true,
+      jsx: true,
+      ..Default::default()
+    }),
+    &|_t| {
+      remove_export_exprs_with_config(config(&options))
+    },
+    &input,
+    &output,
+    FixtureTestConfig {
+      ..Default::default()
+    },
+  );
+}
+
+#[fixture("tests/fixture/dryRun/**/input.js")]
+fn fixture_dry_run(input: PathBuf) {
+  let parent = input.parent().unwrap();
+  let output = parent.join("output.js");
+  let options = fs::read_to_string(parent.join("options.json")).unwrap();
+
+  test_fixture(
+    Syntax::Es(EsSyntax {
+      decorators: true,
+      jsx: true,
+      ..Default::default()
+    }),
+    &|t| {
+      remove_export_exprs_with_comments(config(&options), Some(t.comments.clone()))
+    },
+    &input,
+    &output,
+    FixtureTestConfig {
+      ..Default::default()
+    },
+  );
+}
+
+#[fixture("tests/fixture/diagnostics/**/input.js")]
+fn fixture_diagnostics(input: PathBuf) {
+  let parent = input.parent().unwrap();
+  let output = parent.join("output.js");
+  let options = fs::read_to_string(parent.join("options.json")).unwrap();
+
+  test_fixture(
+    Syntax::Es(EsSyntax {
+      decorators: true,
+      jsx: true,
+      ..Default::default()
+    }),
+    &|_t| {
+      remove_export_exprs_with_config(config(&options))
+    },
+    &input,
+    &output,
+    FixtureTestConfig {
+      allow_error: true,
+      ..Default::default()
+    },
+  );
+}
+
+#[fixture("tests/fixture/manifest/**/input.js")]
+fn fixture_manifest(input: PathBuf) {
+  let parent = input.parent().unwrap();
+  let output = parent.join("output.js");
+  let options = fs::read_to_string(parent.join("options.json")).unwrap();
+
+  test_fixture(
+    Syntax::Es(EsSyntax {
+      decorators: true,
+      jsx: true,
+      ..Default::default()
+    }),
+    &|t| {
+      remove_export_exprs_with_comments(config(&options), Some(t.comments.clone()))
+    },
+    &input,
+    &output,
+    FixtureTestConfig {
+      ..Default::default()
+    },
+  );
+}
+
+#[fixture("tests/fixture/options/**/output.js")]
+fn idempotent_options(output: PathBuf) {
+  let options = fs::read_to_string(output.parent().unwrap().join("options.json")).unwrap();
+
+  test_fixture(
+    Syntax::Es(EsSyntax {
+      decorators: true,
+      jsx: true,
+      import_attributes: true,
+      ..Default::default()
+    }),
+    &|_t| {
+      remove_export_exprs_with_config(config(&options))
+    },
+    &output,
+    &output,
+    FixtureTestConfig {
+      ..Default::default()
+    },
+  );
+}
+
+#[fixture("tests/fixture/preserveData/**/output.js")]
+fn idempotent_preserve_data(output: PathBuf) {
+  test_fixture(
+    Syntax::Es(EsSyntax {
+      decorators: true,
+      jsx: true,
+      ..Default::default()
+    }),
+    &|_t| {
+      remove_export_exprs([String::from("getConfig"), String::from("default")].to_vec())
+    },
+    &output,
+    &output,
+    FixtureTestConfig {
+      ..Default::default()
+    },
+  );
+}
@@ -0,0 +1,16 @@
+import db from './db';
+import { title } from './config';
+
+function readUsers() {
+  return db.users();
+}
+
+export async function getServerSideProps() {
+  return { props: { users: await readUsers() } };
+}
+
+export const getConfig = () => ({ title }), revalidate = 60;
+
+export default function Page({ users }) {
+  return users.length;
+}
@@ -0,0 +1,4 @@
+/* removed by remove-export: readUsers */ /* removed by remove-export: getServerSideProps */ /* removed by remove-export: getConfig */ export const revalidate = 60;
+export default function Page({ users }) {
+    return users.length;
+}
@@ -0,0 +1,8 @@
+// swc-remove-export-disable
+import fs from 'fs';
+export async function getData() {
+    return fs.readFileSync('data.json');
+}
+export default function Page() {
+    return null;
+}
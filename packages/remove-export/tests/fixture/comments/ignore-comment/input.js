@@ -0,0 +1,22 @@
+import { db } from './db'
+import { cache } from './cache'
+
+// swc-remove-export-ignore
+export async function getData() {
+  return db.query()
+}
+
+/* @keep */
+function warmCache() {
+  return cache.warm()
+}
+
+function unused() {
+  return cache.clear()
+}
+
+export const getDataConfig = () => [warmCache(), unused()]
+
+export default function Page() {
+  return null
+}
@@ -0,0 +1,12 @@
+import { db } from './db';
+import { cache } from './cache';
+// swc-remove-export-ignore
+export async function getData() {
+    return db.query();
+}
+/* @keep */ function warmCache() {
+    return cache.warm();
+}
+export default function Page() {
+    return null;
+}
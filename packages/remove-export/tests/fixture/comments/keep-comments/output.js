@@ -0,0 +1,11 @@
+// Page imports
+import { useState } from 'react';
+// Only used by getData
+/**
+ * Loads the data of the page.
+ */ // Helper for getData
+/* Config of the data fetching */ export const /* kept */ revalidate = 60;
+/** Renders the page. */ export default function Page() {
+    const [state] = useState();
+    return state;
+}
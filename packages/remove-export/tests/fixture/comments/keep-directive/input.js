@@ -0,0 +1,15 @@
+/* swc-remove-export: keep getData */
+import fs from 'fs';
+import config from './config';
+
+export async function getData() {
+  return fs.readFileSync('data.json');
+}
+
+export function getConfig() {
+  return config;
+}
+
+export default function Page() {
+  return null;
+}
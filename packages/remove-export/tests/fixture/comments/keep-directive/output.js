@@ -0,0 +1,7 @@
+/* swc-remove-export: keep getData */ import fs from 'fs';
+export async function getData() {
+    return fs.readFileSync('data.json');
+}
+export default function Page() {
+    return null;
+}
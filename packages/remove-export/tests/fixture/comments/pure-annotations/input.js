@@ -0,0 +1,8 @@
+import { render } from './render'
+
+export const config = { title: 'Page' }
+
+/** Renders the page. */
+export default function Page() {
+  return render()
+}
@@ -0,0 +1,4 @@
+export const config = {
+    title: 'Page'
+};
+/** Renders the page. */ export default /*#__PURE__*/ function() {}
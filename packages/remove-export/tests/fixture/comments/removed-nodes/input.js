@@ -0,0 +1,26 @@
+// Page imports
+import { useState } from 'react'
+// Only used by getData
+import { db } from './db'
+
+/**
+ * Loads the data of the page.
+ */
+export async function getData() {
+  // Query the database
+  return db.query() // trailing query
+}
+
+// Helper for getData
+function helper() {
+  return db.all()
+}
+
+/* Config of the data fetching */
+export const getDataConfig = helper(), /* kept */ revalidate = 60
+
+/** Renders the page. */
+export default function Page() {
+  const [state] = useState()
+  return state
+}
@@ -0,0 +1,7 @@
+// Page imports
+import { useState } from 'react';
+/* Config of the data fetching */ export const /* kept */ revalidate = 60;
+/** Renders the page. */ export default function Page() {
+    const [state] = useState();
+    return state;
+}
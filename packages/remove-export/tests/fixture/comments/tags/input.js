@@ -0,0 +1,19 @@
+import { db } from './db';
+
+/**
+ * Reads the users.
+ * @removeOnClient
+ */
+export async function readUsers() {
+  return db.users();
+}
+
+/** @removeOnClientSide */
+export const format = (users) => users.join(', ');
+
+// @removeOnClient
+export const secret = db.secret;
+
+export default function Page() {
+  return null;
+}
@@ -0,0 +1,4 @@
+/** @removeOnClientSide */ export const format = (users)=>users.join(', ');
+export default function Page() {
+    return null;
+}
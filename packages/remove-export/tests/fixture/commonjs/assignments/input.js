@@ -0,0 +1,26 @@
+const db = require('./db')
+const { render } = require('./render')
+
+function query() {
+  return db.query()
+}
+
+exports.getServerSideProps = async function () {
+  return query()
+}
+
+module.exports.getStaticProps = () => db.all()
+
+exports['getData'] = query
+
+exports.Page = function Page() {
+  return render()
+}
+
+const config = { runtime: 'edge' }
+
+module.exports = {
+  config,
+  getData: query,
+  Page: exports.Page,
+}
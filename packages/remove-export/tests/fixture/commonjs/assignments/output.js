@@ -0,0 +1,7 @@
+const { render } = require('./render');
+exports.Page = function Page() {
+    return render();
+};
+module.exports = {
+    Page: exports.Page
+};
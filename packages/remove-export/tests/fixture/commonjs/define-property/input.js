@@ -0,0 +1,9 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.render = exports.getStaticProps = void 0;
+Object.defineProperty(exports, "getStaticProps", { enumerable: true, get: function () { return _data.getStaticProps; } });
+var _data = require("./data");
+function render() {
+  return 'page';
+}
+exports.render = render;
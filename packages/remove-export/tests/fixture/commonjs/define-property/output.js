@@ -0,0 +1,9 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+exports.render = void 0;
+function render() {
+    return 'page';
+}
+exports.render = render;
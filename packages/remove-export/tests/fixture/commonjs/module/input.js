@@ -0,0 +1,6 @@
+import { db } from './db'
+import { render } from './render'
+
+exports.getData = () => db.query()
+
+exports.Page = () => render()
@@ -0,0 +1,2 @@
+import { render } from './render';
+exports.Page = ()=>render();
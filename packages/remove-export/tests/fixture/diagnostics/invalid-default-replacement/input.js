@@ -0,0 +1,5 @@
+import d from './page'
+
+export const config = {}
+
+export default d
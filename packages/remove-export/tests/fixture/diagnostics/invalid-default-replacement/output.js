@@ -0,0 +1,2 @@
+export const config = {};
+export default function() {}
@@ -0,0 +1,11 @@
+import { db } from './db'
+
+const load = () => db.query()
+
+export async function getData() {
+  return load()
+}
+
+export default function Page() {
+  return null
+}
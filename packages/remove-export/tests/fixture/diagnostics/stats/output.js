@@ -0,0 +1,4 @@
+import { render } from './render';
+export default function Page() {
+    return render();
+}
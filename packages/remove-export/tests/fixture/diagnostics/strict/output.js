@@ -0,0 +1,10 @@
+import { db } from './db';
+export async function getData() {
+    return db.query();
+}
+if (typeof getData === 'function') {
+    console.log('data');
+}
+export default function Page() {
+    return null;
+}
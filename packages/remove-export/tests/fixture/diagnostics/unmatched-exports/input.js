@@ -0,0 +1,11 @@
+export async function getServerSideProps() {
+  return { props: {} }
+}
+
+export async function getData() {
+  return null
+}
+
+export default function Page() {
+  return null
+}
@@ -0,0 +1,8 @@
+export async function getServerSideProps() {
+    return {
+        props: {}
+    };
+}
+export default function Page() {
+    return null;
+}
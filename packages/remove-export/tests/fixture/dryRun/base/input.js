@@ -0,0 +1,13 @@
+import { db, cache } from './db'
+import { render, format } from './render'
+
+// Loads the page data.
+const query = () => db.query()
+
+export async function getData() {
+  return format(cache(query()))
+}
+
+export default function Page() {
+  return render()
+}
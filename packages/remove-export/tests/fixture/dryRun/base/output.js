@@ -0,0 +1,10 @@
+/* swc-remove-export: removed */ import { db, cache } from './db';
+import { render, /* swc-remove-export: removed */ format } from './render';
+// Loads the page data.
+/* swc-remove-export: removed */ const query = ()=>db.query();
+/* swc-remove-export: removed */ export async function getData() {
+    return format(cache(query()));
+}
+export default function Page() {
+    return render();
+}
@@ -0,0 +1,5 @@
+import { render } from './render';
+export default function Page() {
+    return render();
+}
+ /* swc-remove-export-manifest {"exports":["getData","config"],"imports":{"./db":["db","cache"],"./render":["format"]},"declarations":["query"]} */ 
@@ -0,0 +1,3 @@
+export const keep = function getData() {
+  return getData;
+};
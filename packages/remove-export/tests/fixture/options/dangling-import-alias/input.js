@@ -0,0 +1,5 @@
+import { getData as g } from "x";
+export function getData() {
+  return g;
+}
+export { g };
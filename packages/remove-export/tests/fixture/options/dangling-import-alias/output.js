@@ -0,0 +1,2 @@
+import { getData as g } from "x";
+export { g };
@@ -0,0 +1,5 @@
+export function keep() {
+  getData: for (;;) {
+    break getData;
+  }
+}
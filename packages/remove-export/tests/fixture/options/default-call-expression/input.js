@@ -0,0 +1,8 @@
+import { createHandler } from './handler'
+import { fetchA, fetchB } from './routes'
+
+export const routeA = () => fetchA()
+
+const routeB = () => fetchB()
+
+export default createHandler(routeA, routeB)
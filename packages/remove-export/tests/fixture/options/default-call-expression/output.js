@@ -0,0 +1,3 @@
+import { fetchA } from './routes';
+export const routeA = ()=>fetchA();
+export default function() {}
@@ -0,0 +1,15 @@
+import { wrap } from './wrap'
+import { a, b, c } from './deps'
+import { keep } from './keep'
+
+const first = () => a()
+const second = () => b()
+const third = c
+
+export const kept = keep
+
+export default wrap({
+  first,
+  routes: [second, (third)],
+  nested: { kept },
+})
@@ -0,0 +1,3 @@
+import { keep } from './keep';
+export const kept = keep;
+export default function() {}
@@ -0,0 +1,5 @@
+import fs from 'fs';
+
+export default async function getData() {
+  return fs.readFileSync('data.json');
+}
@@ -0,0 +1,5 @@
+import fs from 'fs';
+
+export default (function getStaticProps() {
+  return fs.readFileSync('data.json');
+});
@@ -0,0 +1,11 @@
+import fs from 'fs';
+
+export async function getData() {
+  return fs.readFileSync('data.json');
+}
+
+function Page() {
+  return fs.existsSync('page');
+}
+
+export default Page;
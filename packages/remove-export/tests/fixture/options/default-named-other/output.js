@@ -0,0 +1,5 @@
+import fs from 'fs';
+function Page() {
+    return fs.existsSync('page');
+}
+export default Page;
@@ -0,0 +1,9 @@
+import fs from 'fs';
+
+export async function getData() {
+  return fs.readFileSync('data.json');
+}
+
+export default function PageImpl() {
+  return fs.existsSync('page');
+}
@@ -0,0 +1,18 @@
+import { fetchData } from './data'
+import { format } from './format'
+
+const config = { revalidate: 60 }
+
+function getStaticProps() {
+  return fetchData()
+}
+
+function render() {
+  return format()
+}
+
+export default {
+  getStaticProps,
+  config: config,
+  render,
+}
@@ -0,0 +1,7 @@
+import { format } from './format';
+function render() {
+    return format();
+}
+export default {
+    render
+};
@@ -0,0 +1,7 @@
+import { render } from './render'
+
+export const config = { runtime: 'edge' }
+
+export default function Page() {
+  return render()
+}
@@ -0,0 +1,6 @@
+export const config = {
+    runtime: 'edge'
+};
+export default {
+    props: {}
+};
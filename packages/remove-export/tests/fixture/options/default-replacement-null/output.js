@@ -0,0 +1,4 @@
+export const config = {
+    runtime: 'edge'
+};
+export default null;
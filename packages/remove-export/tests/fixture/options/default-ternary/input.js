@@ -0,0 +1,11 @@
+import { isServer } from './env'
+import { renderToString } from './server'
+import { hydrate } from './client'
+
+const serverImpl = () => renderToString()
+
+function clientImpl() {
+  return hydrate()
+}
+
+export default (isServer ? serverImpl : clientImpl)
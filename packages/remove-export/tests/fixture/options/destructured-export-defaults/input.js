@@ -0,0 +1,11 @@
+import { factory, createPair, defaults, dflt, helper } from './factory'
+
+export const [a, b = dflt()] = createPair()
+
+export const { x: c = 1, d } = defaults
+
+export const { getStaticProps = helper(), e } = factory()
+
+export default function Page() {
+  return [a, d, e]
+}
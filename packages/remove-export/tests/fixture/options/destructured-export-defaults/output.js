@@ -0,0 +1,11 @@
+import { factory, createPair, defaults } from './factory';
+export const [a] = createPair();
+export const { d } = defaults;
+export const { e } = factory();
+export default function Page() {
+    return [
+        a,
+        d,
+        e
+    ];
+}
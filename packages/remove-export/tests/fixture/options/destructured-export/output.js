@@ -0,0 +1,11 @@
+import { factory, createPair, defaults } from './factory';
+export const { helper } = factory();
+export const [, loader] = createPair();
+export const { getRest, ...rest } = defaults;
+export default function Page() {
+    return [
+        helper,
+        loader,
+        rest
+    ];
+}
@@ -0,0 +1,5 @@
+export * as clientStuff from './client';
+export * from './shared';
+export default function Page() {
+    return null;
+}
@@ -0,0 +1,7 @@
+export * as serverStuff from './server'
+export * as clientStuff from './client'
+export * from './shared'
+
+export default function Page() {
+  return null
+}
@@ -0,0 +1,4 @@
+export * as clientStuff from './client';
+export default function Page() {
+    return null;
+}
@@ -0,0 +1,12 @@
+import { createServerFn, createClientFn } from '@framework/start';
+import { db } from './db';
+
+export const readUsers = createServerFn({ method: 'GET' }).handler(() => db.users());
+
+export const writeUser = createServerFn((user) => db.write(user));
+
+export const track = createClientFn(() => {});
+
+export default function Page() {
+  return null;
+}
@@ -0,0 +1,5 @@
+import { createClientFn } from '@framework/start';
+export const track = createClientFn(()=>{});
+export default function Page() {
+    return null;
+}
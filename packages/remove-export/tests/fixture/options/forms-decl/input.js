@@ -0,0 +1,14 @@
+import { db } from './db'
+import { config } from './config'
+
+export async function getData() {
+  return db.query()
+}
+
+const getConfig = () => config
+
+export { getConfig }
+
+export default function Page() {
+  return null
+}
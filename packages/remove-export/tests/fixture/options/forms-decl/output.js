@@ -0,0 +1,6 @@
+import { config } from './config';
+const getConfig = ()=>config;
+export { getConfig };
+export default function Page() {
+    return null;
+}
@@ -0,0 +1,7 @@
+import { db } from './db';
+export async function getData() {
+    return db.query();
+}
+export default function Page() {
+    return null;
+}
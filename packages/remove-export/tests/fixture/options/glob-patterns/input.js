@@ -0,0 +1,17 @@
+import { fetchProps, fetchPaths, fetchData } from './server'
+
+export async function getServerSideProps() {
+  return fetchProps()
+}
+
+export const getStaticProps = () => fetchProps()
+
+export const loadServerData = () => fetchData()
+
+export async function getStaticPaths() {
+  return fetchPaths()
+}
+
+export default function Page() {
+  return null
+}
@@ -0,0 +1,7 @@
+import { fetchPaths } from './server';
+export async function getStaticPaths() {
+    return fetchPaths();
+}
+export default function Page() {
+    return null;
+}
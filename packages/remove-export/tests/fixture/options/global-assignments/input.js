@@ -0,0 +1,13 @@
+import { db } from './db';
+
+export function getStaticProps() {
+  return db.query();
+}
+
+export function render() {
+  return null;
+}
+
+globalThis.__DATA_FN__ = getStaticProps;
+window['__LOADERS__'] = [getStaticProps];
+globalThis.__RENDER__ = render;
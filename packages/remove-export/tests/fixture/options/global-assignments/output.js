@@ -0,0 +1,4 @@
+export function render() {
+    return null;
+}
+globalThis.__RENDER__ = render;
@@ -0,0 +1,12 @@
+import data from './data.json' with { type: 'json' };
+import { locale, messages } from './i18n.json' with { type: 'json' };
+import './theme.css' with { type: 'css' };
+import './reset.css';
+
+export function getData() {
+  return { data, messages };
+}
+
+export default function Page() {
+  return locale;
+}
@@ -0,0 +1,9 @@
+import { locale } from './i18n.json' with {
+    type: 'json'
+};
+import './theme.css' with {
+    type: 'css'
+};
+export default function Page() {
+    return locale;
+}
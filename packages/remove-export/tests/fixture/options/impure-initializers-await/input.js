@@ -0,0 +1,8 @@
+import { loadData } from './data';
+import { format } from './format';
+
+export const data = format(await loadData());
+
+export default function Page() {
+  return null;
+}
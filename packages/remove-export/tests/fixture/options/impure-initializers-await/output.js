@@ -0,0 +1,6 @@
+import { loadData } from './data';
+import { format } from './format';
+format(await loadData());
+export default function Page() {
+    return null;
+}
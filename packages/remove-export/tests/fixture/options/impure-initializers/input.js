@@ -0,0 +1,10 @@
+import { fetchAtBuildTime } from './fetch';
+import { load } from './load';
+
+export const data = fetchAtBuildTime(), config = { debug: true };
+
+export const getData = () => load();
+
+export const init = (() => {
+  fetchAtBuildTime();
+})();
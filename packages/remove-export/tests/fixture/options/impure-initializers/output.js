@@ -0,0 +1,5 @@
+import { fetchAtBuildTime } from './fetch';
+fetchAtBuildTime();
+export const init = (()=>{
+    fetchAtBuildTime();
+})();
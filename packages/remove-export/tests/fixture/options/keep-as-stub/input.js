@@ -0,0 +1,11 @@
+import { db } from './db'
+
+export async function getServerSideProps() {
+  return { props: { items: await db.items() } }
+}
+
+export const config = { runtime: 'edge' }
+
+export default function Page({ items }) {
+  return items
+}
@@ -0,0 +1,9 @@
+export const getServerSideProps = ()=>({
+        props: {}
+    });
+export const config = {
+    runtime: 'edge'
+};
+export default function Page({ items }) {
+    return items;
+}
@@ -0,0 +1,19 @@
+import { db } from './db';
+import { factory } from './factory';
+export const version = 1;
+export const { helper } = factory();
+const shared = ()=>db.shared();
+const cache = new Map();
+export default function Page() {
+    return [
+        helper,
+        shared,
+        cache
+    ];
+}
+export const getData = undefined;
+export const getStaticProps = undefined;
+export const getServerSideProps = undefined;
+export const getInitialProps = undefined;
+const _cache = undefined;
+export { _cache as cache };
@@ -0,0 +1,19 @@
+import { db } from './db'
+import { config } from './config'
+import { Base } from './base'
+
+function query() {
+  return db.query()
+}
+
+export async function getData() {
+  return query()
+}
+
+export const getConfig = () => config
+
+export class Loader extends Base {}
+
+export default function Page() {
+  return null
+}
@@ -0,0 +1,6 @@
+export const getData = undefined;
+export const getConfig = undefined;
+export const Loader = undefined;
+export default function Page() {
+    return null;
+}
@@ -0,0 +1,16 @@
+import { Model } from './model'
+import { title } from './title'
+
+export const config = { title }
+
+export class Store extends Model {
+  load() {
+    return this.fetch()
+  }
+}
+
+export default class Page {
+  render() {
+    return config.title
+  }
+}
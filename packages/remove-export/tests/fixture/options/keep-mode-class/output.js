@@ -0,0 +1,9 @@
+import { title } from './title';
+export const config = {
+    title
+};
+export default class Page {
+    render() {
+        return config.title;
+    }
+}
@@ -0,0 +1,14 @@
+import { db } from './db'
+import { title } from './title'
+
+export const config = { title }
+
+export async function getServerSideProps() {
+  return db.query()
+}
+
+export const getStaticProps = () => db.all()
+
+export default function Page() {
+  return config.title
+}
@@ -0,0 +1,7 @@
+import { title } from './title';
+export const config = {
+    title
+};
+export default function Page() {
+    return config.title;
+}
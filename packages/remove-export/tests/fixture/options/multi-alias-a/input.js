@@ -0,0 +1,15 @@
+import { db } from './db'
+
+function query() {
+  return db.query()
+}
+
+function x() {
+  return query()
+}
+
+export { x as a, x as b }
+
+export default function Page() {
+  return null
+}
@@ -0,0 +1,11 @@
+import { db } from './db';
+function query() {
+    return db.query();
+}
+function x() {
+    return query();
+}
+export { x as b };
+export default function Page() {
+    return null;
+}
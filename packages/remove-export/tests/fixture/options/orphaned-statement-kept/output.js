@@ -0,0 +1,13 @@
+import { registry } from './registry'
+import { fetchProps } from './server'
+
+export async function getServerSideProps() {
+  return fetchProps()
+}
+
+registry.set('gssp', getServerSideProps)
+registry.set('page', Page)
+
+export default function Page() {
+  return null
+}
@@ -0,0 +1,7 @@
+import { registry } from './registry'
+
+registry.set('page', Page)
+
+export default function Page() {
+  return null
+}
@@ -0,0 +1,7 @@
+import fs from 'fs';
+
+export function getData() {
+  return fs.readFileSync('data.json');
+}
+
+console.log('loaded');
@@ -0,0 +1,18 @@
+import { rerender } from './hmr';
+import fs from 'fs';
+
+export function getData() {
+  return fs.readFileSync('data.json');
+}
+
+export function render() {
+  return null;
+}
+
+module.hot.accept('./page', () => rerender(getData));
+
+if (import.meta.hot) {
+  import.meta.hot.accept(() => rerender(getData));
+}
+
+module.hot && module.hot.accept(() => rerender(getData));
@@ -0,0 +1,9 @@
+export { getStaticProps } from './data/props'
+export { getStaticProps as getLegacyProps, getData as getLegacyData } from './legacy'
+export { getData as default } from './data/default'
+
+import { getData as loadData } from './data/load'
+
+const getData = () => loadData()
+
+export { getData }
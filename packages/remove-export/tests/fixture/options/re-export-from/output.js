@@ -0,0 +1,2 @@
+export { getStaticProps as getLegacyProps, getData as getLegacyData } from './legacy';
+export { getData as default } from './data/default';
@@ -0,0 +1,13 @@
+export { default as LegacyPage } from './legacy'
+export { default as NewPage } from './new'
+export { "server-data" } from './server'
+export { getData as "client-data" } from './client'
+export { "raw-data" as getData } from './raw'
+export * as serverNs from './server'
+export * as "server-ns" from './server'
+
+import { load } from './load'
+
+const getData = () => load()
+
+export { getData as "local-data" }
@@ -0,0 +1,3 @@
+export { default as NewPage } from './new';
+export { getData as "client-data" } from './client';
+export * as "server-ns" from './server';
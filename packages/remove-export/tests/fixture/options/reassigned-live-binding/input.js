@@ -0,0 +1,18 @@
+export let state = 0
+
+export function format(value) {
+  return String(value)
+}
+
+export function getData() {
+  state = 1
+  return format(state)
+}
+
+export function increment() {
+  state += 1
+}
+
+export function install(custom) {
+  format = custom
+}
@@ -0,0 +1,13 @@
+export let state = 0
+
+export function format(value) {
+  return String(value)
+}
+
+export function increment() {
+  state += 1
+}
+
+export function install(custom) {
+  format = custom
+}
@@ -0,0 +1,14 @@
+import { secret } from './secret'
+import { version } from './version'
+
+export const internal_secret = () => secret()
+
+export function __generated__() {
+  return secret
+}
+
+export const publicVersion = version
+
+export default function Page() {
+  return null
+}
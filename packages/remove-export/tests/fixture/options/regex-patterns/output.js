@@ -0,0 +1,5 @@
+import { version } from './version';
+export const publicVersion = version;
+export default function Page() {
+    return null;
+}
@@ -0,0 +1,18 @@
+import { db } from './db'
+import { factory } from './factory'
+
+export const getData = () => db.query(), version = 1
+
+export const { getStaticProps, helper } = factory()
+
+const load = () => db.load()
+
+export { load as getServerSideProps }
+
+const shared = () => db.shared()
+
+export { shared as getInitialProps }
+
+export default function Page() {
+  return [helper, shared]
+}
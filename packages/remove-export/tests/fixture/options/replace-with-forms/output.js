@@ -0,0 +1,23 @@
+import { db } from './db';
+import { factory } from './factory';
+export const version = 1;
+export const { helper } = factory();
+const shared = ()=>db.shared();
+export default function Page() {
+    return [
+        helper,
+        shared
+    ];
+}
+export const getData = ()=>{
+    throw new Error("server only");
+};
+export const getStaticProps = ()=>{
+    throw new Error("server only");
+};
+export const getServerSideProps = ()=>{
+    throw new Error("server only");
+};
+export const getInitialProps = ()=>{
+    throw new Error("server only");
+};
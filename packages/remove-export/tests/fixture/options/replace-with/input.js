@@ -0,0 +1,8 @@
+import db from './db';
+export async function getStaticProps() {
+  return { props: await db.read() };
+}
+export const getConfig = () => ({ title: 'Page' });
+export default function Page() {
+  return 'page';
+}
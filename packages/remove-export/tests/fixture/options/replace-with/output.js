@@ -0,0 +1,9 @@
+export const getStaticProps = ()=>{
+    throw new Error("server only");
+};
+export const getConfig = ()=>{
+    throw new Error("server only");
+};
+export default function Page() {
+    return 'page';
+}
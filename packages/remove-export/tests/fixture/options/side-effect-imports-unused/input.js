@@ -0,0 +1,5 @@
+import './server-polyfill'
+
+export default function Page({ items }) {
+  return items
+}
@@ -0,0 +1,4 @@
+import './server-polyfill';
+export default function Page({ items }) {
+    return items;
+}
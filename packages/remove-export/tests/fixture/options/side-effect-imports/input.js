@@ -0,0 +1,11 @@
+import './server-polyfill'
+import './styles.css'
+import { db } from './db'
+
+export async function getServerSideProps() {
+  return { props: { items: await db.items() } }
+}
+
+export default function Page({ items }) {
+  return items
+}
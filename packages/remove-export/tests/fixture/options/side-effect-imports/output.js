@@ -0,0 +1,4 @@
+import './styles.css';
+export default function Page({ items }) {
+    return items;
+}
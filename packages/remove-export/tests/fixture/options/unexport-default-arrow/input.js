@@ -0,0 +1,3 @@
+import d from './page'
+
+export default () => d
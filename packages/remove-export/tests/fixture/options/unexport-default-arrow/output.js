@@ -0,0 +1,2 @@
+import d from './page';
+()=>d;
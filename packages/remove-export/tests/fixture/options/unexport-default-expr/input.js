@@ -0,0 +1,5 @@
+import { register } from './registry'
+
+export const config = {}
+
+export default register(config)
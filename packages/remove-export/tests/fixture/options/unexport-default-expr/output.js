@@ -0,0 +1,3 @@
+import { register } from './registry';
+export const config = {};
+register(config);
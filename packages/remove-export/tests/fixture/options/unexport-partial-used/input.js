@@ -0,0 +1,7 @@
+import { o } from './o'
+
+export const a = 1, b = 2
+
+export const { c, d } = o
+
+console.log(a, c)
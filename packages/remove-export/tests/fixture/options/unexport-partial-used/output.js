@@ -0,0 +1,6 @@
+import { o } from './o';
+const a = 1;
+export const b = 2;
+const { c, d } = o;
+export { d };
+console.log(a, c);
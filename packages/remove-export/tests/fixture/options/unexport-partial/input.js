@@ -0,0 +1,7 @@
+import { o } from './o'
+
+export const a = 1, b = 2
+
+export const { c, d } = o
+
+export let e = 3, f = 4, g = 5
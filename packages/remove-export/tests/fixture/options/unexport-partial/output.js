@@ -0,0 +1,8 @@
+import { o } from './o';
+const a = 1;
+export const b = 2;
+const { c, d } = o;
+export { d };
+export let e = 3;
+let f = 4;
+export let g = 5;
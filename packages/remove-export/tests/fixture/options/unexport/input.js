@@ -0,0 +1,17 @@
+import { db } from './db'
+
+export async function getData() {
+  return db.query()
+}
+
+export const config = { runtime: 'edge' }
+
+const helper = () => getData()
+
+export { helper }
+
+export default function Page() {
+  return helper()
+}
+
+registry.set(config, Page)
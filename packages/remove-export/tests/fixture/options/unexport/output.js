@@ -0,0 +1,12 @@
+import { db } from './db';
+async function getData() {
+    return db.query();
+}
+const config = {
+    runtime: 'edge'
+};
+const helper = ()=>getData();
+function Page() {
+    return helper();
+}
+registry.set(config, Page);
@@ -0,0 +1,20 @@
+import { db } from './db'
+import { cache } from './cache'
+
+export async function getData() {
+  return db.query()
+}
+
+async function getProps() {
+  return cache.get()
+}
+
+export function getConfig() {
+  return {}
+}
+
+export const load = async () => {
+  return db.load()
+}
+
+export { getProps as getStaticProps }
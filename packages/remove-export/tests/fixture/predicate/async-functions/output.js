@@ -0,0 +1,9 @@
+import { db } from './db'
+
+export function getConfig() {
+  return {}
+}
+
+export const load = async () => {
+  return db.load()
+}
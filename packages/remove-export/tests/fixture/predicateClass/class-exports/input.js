@@ -0,0 +1,9 @@
+import { Model } from './model'
+
+export class Store extends Model {}
+
+export function createStore() {
+  return {}
+}
+
+export const store = createStore()
@@ -0,0 +1,4 @@
+export function createStore() {
+    return {};
+}
+export const store = createStore();
@@ -0,0 +1,15 @@
+import { Base, Widget } from './base'
+import { observer, observable } from './decorators'
+import { helper } from './helper'
+
+export const config = { title: 'Page' }
+
+export class Header extends Widget {}
+
+export default @observer class Page extends Base {
+  @observable items = []
+
+  render() {
+    return helper(this.items)
+  }
+}
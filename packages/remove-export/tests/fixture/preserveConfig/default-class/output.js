@@ -0,0 +1,7 @@
+import { Widget } from './base';
+export const config = {
+    title: 'Page'
+};
+export class Header extends Widget {
+}
+export default function() {}
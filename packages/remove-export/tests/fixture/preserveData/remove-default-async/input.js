@@ -0,0 +1,5 @@
+import { load } from './load';
+
+export default async function Page() {
+  return await load();
+}
@@ -0,0 +1,5 @@
+import { items } from './items';
+
+export default function* Page() {
+  yield* items;
+}
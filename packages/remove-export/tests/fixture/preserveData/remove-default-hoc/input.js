@@ -0,0 +1,13 @@
+import { withAuth } from './auth'
+import { Layout } from './layout'
+import { fetchData } from './data'
+
+function PageImpl() {
+  return <Layout />
+}
+
+export async function getData() {
+  return fetchData()
+}
+
+export default withAuth(PageImpl)
@@ -0,0 +1,5 @@
+import { fetchData } from './data';
+export async function getData() {
+    return fetchData();
+}
+export default function() {}
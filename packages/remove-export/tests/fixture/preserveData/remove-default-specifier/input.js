@@ -0,0 +1,13 @@
+import { useData } from 'ice';
+import fs from 'fs';
+
+function pageImpl() {
+  const data = useData();
+  return fs.existsSync(data);
+}
+
+export function render() {
+  return null;
+}
+
+export { pageImpl as default };
@@ -5,4 +5,4 @@ export async function getData() {
     b;
     rest;
 }
-export default function() {};
+export default function() {}
@@ -1,6 +1,6 @@
 import fs from 'fs';
 import other from 'other';
-const [b] = fs.promises;
+const [, b] = fs.promises;
 const [foo] = other;
 export default function Home() {
     console.log(b);
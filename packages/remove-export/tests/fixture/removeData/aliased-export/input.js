@@ -0,0 +1,17 @@
+import { fetchItems } from './api'
+
+async function internalImpl() {
+  return { props: { items: await fetchItems() } }
+}
+
+export { internalImpl as getData }
+
+export default function Page({ items }) {
+  return items
+}
+
+function getData() {
+  return fetchItems()
+}
+
+export { getData as loader }
@@ -0,0 +1,8 @@
+import { fetchItems } from './api';
+export default function Page({ items }) {
+    return items;
+}
+function getData() {
+    return fetchItems();
+}
+export { getData as loader };
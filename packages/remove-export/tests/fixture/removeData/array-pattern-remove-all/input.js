@@ -0,0 +1,11 @@
+import { load } from './load'
+
+const [a, b, c] = load()
+
+export function getData() {
+  return [a, b, c]
+}
+
+export function getConfig() {
+  return null
+}
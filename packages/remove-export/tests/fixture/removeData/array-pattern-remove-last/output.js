@@ -0,0 +1,8 @@
+import { load } from './load';
+const [a, b] = load();
+export function getConfig() {
+    return [
+        a,
+        b
+    ];
+}
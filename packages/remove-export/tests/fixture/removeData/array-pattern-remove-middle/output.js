@@ -0,0 +1,8 @@
+import { load } from './load';
+const [a, , c] = load();
+export function getConfig() {
+    return [
+        a,
+        c
+    ];
+}
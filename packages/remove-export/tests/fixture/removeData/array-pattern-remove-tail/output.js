@@ -0,0 +1,5 @@
+import { load } from './load';
+const [a] = load();
+export function getConfig() {
+    return a;
+}
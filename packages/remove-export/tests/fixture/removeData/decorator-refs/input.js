@@ -0,0 +1,18 @@
+import { inject } from 'di';
+import { DataToken } from './tokens';
+
+const dataToken = new DataToken();
+
+export function getData() {
+  return dataToken;
+}
+
+@inject(dataToken)
+class Service {}
+
+@inject(getData)
+class Loader {}
+
+export default function Page() {
+  return [Service, Loader];
+}
@@ -0,0 +1,14 @@
+import { inject } from 'di';
+import { DataToken } from './tokens';
+const dataToken = new DataToken();
+@inject(dataToken)
+class Service {
+}
+class Loader {
+}
+export default function Page() {
+    return [
+        Service,
+        Loader
+    ];
+}
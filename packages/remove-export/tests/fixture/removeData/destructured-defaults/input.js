@@ -0,0 +1,13 @@
+import { createPair, defaults, dflt } from './factory'
+
+const [a, b = dflt()] = createPair()
+
+const { x: c = 1, d } = defaults
+
+export async function getData() {
+  return [b, c]
+}
+
+export default function Page() {
+  return [a, d]
+}
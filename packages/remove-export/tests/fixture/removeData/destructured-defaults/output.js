@@ -0,0 +1,9 @@
+import { createPair, defaults } from './factory';
+const [a] = createPair();
+const { d } = defaults;
+export default function Page() {
+    return [
+        a,
+        d
+    ];
+}
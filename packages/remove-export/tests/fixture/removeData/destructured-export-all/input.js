@@ -0,0 +1,7 @@
+import { factory } from './factory'
+
+export const { getData } = factory()
+
+export default function Page() {
+  return null
+}
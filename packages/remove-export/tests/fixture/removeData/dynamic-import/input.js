@@ -0,0 +1,14 @@
+const loadHeavy = () => import('./heavy-server-lib')
+const { format } = await import('./format')
+const pending = import('./pending')
+
+export async function getData() {
+  const mod = await import('./inline')
+  const heavy = await loadHeavy()
+  await pending
+  return { props: { data: heavy.run(mod), text: format() } }
+}
+
+export default function Page() {
+  return null
+}
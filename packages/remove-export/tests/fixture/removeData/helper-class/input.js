@@ -0,0 +1,21 @@
+import { Client } from './client'
+import { Base } from './base'
+
+class Fetcher extends Base {
+  constructor() {
+    super()
+    this.client = new Client()
+  }
+
+  fetch() {
+    return this.client.get('/items')
+  }
+}
+
+export async function getData() {
+  return new Fetcher().fetch()
+}
+
+export default function Page({ items }) {
+  return items
+}
@@ -0,0 +1,14 @@
+import { Layout } from './layout';
+import * as UI from './ui';
+
+function Preview({ data }) {
+  return <Layout><UI.Card data={data} /></Layout>;
+}
+
+export function getData() {
+  return { preview: <Preview data={1} /> };
+}
+
+export default function Page() {
+  return <UI.Button />;
+}
@@ -0,0 +1,4 @@
+import * as UI from './ui';
+export default function Page() {
+    return <UI.Button/>;
+}
@@ -0,0 +1,10 @@
+import { fetchData } from './data'
+import { title } from './title'
+
+export const revalidate = 60, getData = () => fetchData(), pageTitle = title
+
+export const first = 1, second = 2
+
+export default function Page() {
+  return pageTitle
+}
@@ -0,0 +1,6 @@
+import { title } from './title';
+export const revalidate = 60, pageTitle = title;
+export const first = 1, second = 2;
+export default function Page() {
+    return pageTitle;
+}
@@ -0,0 +1,8 @@
+import {} from './polyfill';
+import fs from 'fs';
+
+export function getConfig() {
+  return fs.readFileSync('config.json');
+};
+
+export {};
@@ -0,0 +1,7 @@
+import './polyfill';
+import fs from 'fs';
+export function getConfig() {
+    return fs.readFileSync('config.json');
+}
+;
+export { };
@@ -0,0 +1,12 @@
+import { wrap } from './wrap'
+import { fetchItems } from './api'
+
+export let getData = async () => {
+  return { props: { items: await fetchItems() } }
+}
+
+getData = wrap(getData)
+
+export default function Page({ items }) {
+  return items
+}
@@ -0,0 +1,15 @@
+import { db } from './db'
+
+function walk(node) {
+  return node ? [node, ...walk(node.next)] : []
+}
+
+const load = () => walk(db.root())
+
+export async function getData() {
+  return load()
+}
+
+export default function Page() {
+  return null
+}
@@ -0,0 +1,22 @@
+import { fetchJson } from './fetch'
+import { transform } from './transform'
+
+const endpoint = '/api'
+const [load, , unused] = [() => fetchJson(endpoint), null, 1]
+
+function normalize(data) {
+  const transform = (value) => value
+  return transform(data)
+}
+
+function prepare(data) {
+  return normalize(transform(data))
+}
+
+export async function getData() {
+  return prepare(await load())
+}
+
+export function getConfig() {
+  return { endpoint: '/config', unused }
+}
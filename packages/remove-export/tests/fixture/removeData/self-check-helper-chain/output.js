@@ -0,0 +1,13 @@
+import { fetchJson } from './fetch';
+const endpoint = '/api';
+const [, , unused] = [
+    ()=>fetchJson(endpoint),
+    null,
+    1
+];
+export function getConfig() {
+    return {
+        endpoint: '/config',
+        unused
+    };
+}
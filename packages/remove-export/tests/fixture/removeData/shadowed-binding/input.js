@@ -0,0 +1,13 @@
+import { db } from './db'
+import { useLoader } from './loader'
+
+const load = () => db.query()
+
+export async function getData() {
+  return load()
+}
+
+export function Page() {
+  const load = useLoader()
+  return null
+}
@@ -0,0 +1,5 @@
+import { useLoader } from './loader';
+export function Page() {
+    const load = useLoader();
+    return null;
+}
@@ -0,0 +1,21 @@
+import styled, { css } from 'styled-components'
+import { Base } from './base'
+
+const primaryColor = (props) => props.theme.primary
+const spacing = (n) => `${n * 4}px`
+
+export function getData() {
+  const Button = styled(Base).attrs((props) => ({ color: primaryColor(props) }))`
+    color: ${primaryColor};
+  `
+  const Title = styled.h1`
+    ${css`
+      margin: ${spacing(2)};
+    `}
+  `
+  return [Button, Title]
+}
+
+export function getConfig() {
+  return {}
+}
@@ -0,0 +1,21 @@
+import styled from 'styled-components'
+import { Base } from './base'
+import { theme } from './theme'
+
+const primaryColor = (props) => props.theme.primary
+
+const Button = styled(Base).attrs((props) => ({ color: primaryColor(props) }))`
+  color: ${primaryColor};
+`
+
+const Title = styled.h1`
+  color: ${theme.text};
+`
+
+export function getData() {
+  return Button
+}
+
+export function getConfig() {
+  return Title
+}
@@ -0,0 +1,8 @@
+import styled from 'styled-components';
+import { theme } from './theme';
+const Title = styled.h1`
+  color: ${theme.text};
+`;
+export function getConfig() {
+    return Title;
+}
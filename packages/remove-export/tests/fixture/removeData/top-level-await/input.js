@@ -0,0 +1,10 @@
+import { loadData } from './data';
+import { loadConfig } from './config';
+
+export const getData = await loadData();
+
+export const config = await loadConfig();
+
+export default function Page() {
+  return config;
+}
@@ -0,0 +1,5 @@
+import { loadConfig } from './config';
+export const config = await loadConfig();
+export default function Page() {
+    return config;
+}
@@ -0,0 +1,28 @@
+import { BASE } from './constants';
+import { Shift } from './shift';
+
+export enum Mode {
+  Read = BASE,
+  Write = BASE + 1,
+}
+
+export const enum Flags {
+  None = 0,
+  All = 1 << Shift,
+}
+
+export enum Kept {
+  A,
+}
+
+export default function Page() {
+  return Kept.A;
+}
+
+enum Source {
+  Disk = BASE,
+}
+
+export function getData() {
+  return Source.Disk;
+}
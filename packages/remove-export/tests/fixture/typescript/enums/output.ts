@@ -0,0 +1,6 @@
+export enum Kept {
+    A
+}
+export default function Page() {
+    return Kept.A;
+}
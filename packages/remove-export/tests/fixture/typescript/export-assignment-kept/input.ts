@@ -0,0 +1,7 @@
+import { db } from './db'
+
+function someServerFn() {
+  return db.query()
+}
+
+export = someServerFn
@@ -0,0 +1,5 @@
+import { db } from './db';
+function someServerFn() {
+    return db.query();
+}
+export = someServerFn
@@ -0,0 +1,9 @@
+import { db } from './db'
+
+function someServerFn() {
+  return db.query()
+}
+
+export = someServerFn
+
+console.log('loaded')
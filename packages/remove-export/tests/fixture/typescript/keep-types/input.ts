@@ -0,0 +1,26 @@
+import type { GetServerSidePropsContext } from 'next'
+import { fetchProps } from './server'
+
+export interface Props {
+  title: string
+}
+
+export type GetServerSideProps = (context: GetServerSidePropsContext) => Promise<Props>
+
+export const getServerSideProps: GetServerSideProps = async (context) => {
+  return fetchProps(context)
+}
+
+type DataResult = { items: string[] }
+
+export interface getData {
+  result: DataResult
+}
+
+export async function getData(): Promise<DataResult> {
+  return { items: [] }
+}
+
+export default function Page(props: Props) {
+  return props.title
+}
@@ -0,0 +1,14 @@
+import type { GetServerSidePropsContext } from 'next';
+export interface Props {
+    title: string;
+}
+export type GetServerSideProps = (context: GetServerSidePropsContext) => Promise<Props>;
+type DataResult = {
+    items: string[];
+};
+export interface getData {
+    result: DataResult;
+}
+export default function Page(props: Props) {
+    return props.title;
+}
@@ -0,0 +1,26 @@
+import { db } from './db'
+import { format } from './format'
+import { log } from './log'
+import type { Item } from './types'
+
+namespace Queries {
+  export const items = () => db.query('items')
+}
+
+export namespace ServerUtils {
+  export function load(): Item[] {
+    return format(Queries.items())
+  }
+}
+
+export namespace ClientUtils {
+  export const print = (value: string) => log(value)
+}
+
+export async function getData() {
+  return ServerUtils.load()
+}
+
+export default function Page() {
+  return ClientUtils.print('page')
+}
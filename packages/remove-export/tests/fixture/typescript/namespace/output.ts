@@ -0,0 +1,7 @@
+import { log } from './log';
+export namespace ClientUtils {
+    export const print = (value: string)=>log(value);
+}
+export default function Page() {
+    return ClientUtils.print('page');
+}
@@ -0,0 +1,20 @@
+import type { X, Y, Loader } from './types';
+import { db } from './db';
+
+export function getData(a: string): X;
+export function getData(a: number): Y;
+export function getData(a: any) {
+  return db.query(a);
+}
+
+function format(a: string): string;
+function format(a: number): string;
+function format(a: any) {
+  return String(a);
+}
+
+export default function Page() {
+  return format(1);
+}
+
+export const getConfig: Loader = () => null;
@@ -0,0 +1,8 @@
+function format(a: string): string;
+function format(a: number): string;
+function format(a: any) {
+    return String(a);
+}
+export default function Page() {
+    return format(1);
+}
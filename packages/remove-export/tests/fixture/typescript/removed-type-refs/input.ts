@@ -0,0 +1,12 @@
+import { db } from './db';
+
+export async function getStaticProps() {
+  return { props: await db.query() };
+}
+
+type Props = Awaited<ReturnType<typeof getStaticProps>>['props'];
+
+export default function Page(props: Props, extra: typeof getStaticProps): ReturnType<typeof getStaticProps> | null {
+  const local: typeof getStaticProps | undefined = undefined;
+  return null;
+}
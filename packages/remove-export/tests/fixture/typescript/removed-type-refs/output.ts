@@ -0,0 +1,5 @@
+type Props = Awaited<ReturnType<any>>['props'];
+export default function Page(props: Props, extra: any): ReturnType<any> | null {
+    const local: any | undefined = undefined;
+    return null;
+}
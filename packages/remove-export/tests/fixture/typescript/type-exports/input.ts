@@ -0,0 +1,25 @@
+import type { Query } from './query'
+import type { Row } from './row'
+import { type Cache, type Keep, store } from './cache'
+import type { PageProps } from './page'
+
+export interface DataProps {
+  query: Query
+  cache: Cache
+}
+
+type Local = { row: Row }
+
+export type DataResult = Local[]
+
+export type { Local as Shared }
+
+export type Kept = Keep
+
+export async function getData() {
+  return store
+}
+
+export default function Page(props: PageProps) {
+  return props
+}
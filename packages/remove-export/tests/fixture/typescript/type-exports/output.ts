@@ -0,0 +1,10 @@
+import type { Row } from './row';
+import { type Keep } from './cache';
+import type { PageProps } from './page';
+type Local = {
+    row: Row;
+};
+export type Kept = Keep;
+export default function Page(props: PageProps) {
+    return props;
+}
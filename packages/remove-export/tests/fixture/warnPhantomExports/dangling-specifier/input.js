@@ -0,0 +1,9 @@
+import { db } from './db'
+
+const getConfig = () => ({ title: '' })
+
+export function getData() {
+  return db.query()
+}
+
+export { getConfig, ghost }
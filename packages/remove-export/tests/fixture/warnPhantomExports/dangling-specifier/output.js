@@ -0,0 +1,3 @@
+const getConfig = () => ({ title: '' })
+
+export { getConfig, ghost }
@@ -0,0 +1,48 @@
+use swc_core::ecma::{
+  transforms::{base::pass::noop, testing::Tester},
+  visit::Fold,
+};
+use swc_plugin_remove_export::remove_export_exprs;
+
+fn transform(tester: &mut Tester, tr: &mut impl Fold, name: &str, src: &str) -> String {
+  let program = tester
+    .apply_transform(tr, name, Default::default(), Some(true), src)
+    .unwrap();
+  tester.print(&program, &tester.comments.clone())
+}
+
+fn normalize(tester: &mut Tester, src: &str) -> String {
+  transform(tester, &mut noop(), "expected.js", src)
+}
+
+#[test]
+fn reuse_transform_across_modules() {
+  Tester::run(|tester| {
+    let mut tr = remove_export_exprs(vec![String::from("getData")]);
+
+    let first = transform(
+      tester,
+      &mut tr,
+      "first.js",
+      "const shared = 1;
+      export function getData() { return shared; }
+      export function getConfig() {}",
+    );
+    assert_eq!(first, normalize(tester, "export function getConfig() {}"));
+
+    // `shared` was a data dependency of the first module only.
+    let second = transform(
+      tester,
+      &mut tr,
+      "second.js",
+      "const shared = 2;
+      export function getConfig() {}",
+    );
+    assert_eq!(
+      second,
+      normalize(tester, "const shared = 2; export function getConfig() {}")
+    );
+
+    Ok(())
+  });
+}
@@ -0,0 +1,264 @@
+use serde::Deserialize;
+use std::mem::take;
+use swc_common::{collections::AHashSet, DUMMY_SP};
+use swc_common::errors::HANDLER;
+use swc_core::ecma::{
+    ast::*,
+    atoms::atom,
+    utils::{quote_ident, ExprFactory},
+    visit::{as_folder, noop_visit_mut_type, noop_visit_type, Fold, FoldWith, Visit, VisitMut, VisitMutWith, VisitWith},
+};
+use swc_plugin_proxy::TransformPluginProgramMetadata;
+use swc_plugin_macro::plugin_transform;
+
+/// Options of remove-prop-types.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    #[serde(default)]
+    pub mode: Mode,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Mode {
+    /// Remove the prop types, and the imports of `prop-types` left unused.
+    #[default]
+    Remove,
+    /// Only assign the prop types if `process.env.NODE_ENV !== "production"`.
+    Wrap,
+}
+
+/// Removes the prop types of React components: `Component.propTypes = {...}`,
+/// `static propTypes = {...}` and the imports of `prop-types`.
+pub fn remove_prop_types(config: Config) -> impl Fold {
+    as_folder(RemovePropTypes { config })
+}
+
+struct RemovePropTypes {
+    config: Config,
+}
+
+impl RemovePropTypes {
+    /// Returns the statements replacing `s`.
+    fn replace_stmt(&self, mut s: Stmt) -> Vec<Stmt> {
+        if let Stmt::Expr(ExprStmt { expr, .. }) = &s {
+            if is_prop_types_assign(expr) {
+                return match self.config.mode {
+                    Mode::Remove => vec![],
+                    Mode::Wrap => vec![dev_only(vec![s])],
+                };
+            }
+        }
+
+        if let Stmt::Decl(Decl::Class(ClassDecl { ident, class, .. })) = &mut s {
+            if let Some(stmt) = self.wrap_static_prop_types(ident, class) {
+                return vec![s, stmt];
+            }
+        }
+        vec![s]
+    }
+
+    /// Takes the `static propTypes` of the class `name` out of it, returning the
+    /// guarded assignment replacing them with [Mode::Wrap].
+    fn wrap_static_prop_types(&self, name: &Ident, class: &mut Class) -> Option<Stmt> {
+        if self.config.mode != Mode::Wrap {
+            return None;
+        }
+        let value = take_static_prop_types(class)?;
+
+        let assign = AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: name.clone().make_member(quote_ident!("propTypes")).into(),
+            right: value,
+        };
+        Some(dev_only(vec![assign.into_stmt()]))
+    }
+}
+
+impl VisitMut for RemovePropTypes {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        m.visit_mut_children_with(self);
+
+        if self.config.mode == Mode::Remove {
+            remove_unused_imports(m);
+        }
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.visit_mut_children_with(self);
+
+        for item in take(items) {
+            match item {
+                ModuleItem::Stmt(s) => items.extend(self.replace_stmt(s).into_iter().map(ModuleItem::Stmt)),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    span,
+                    decl: Decl::Class(mut c),
+                })) => {
+                    let stmt = self.wrap_static_prop_types(&c.ident, &mut c.class);
+                    items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                        span,
+                        decl: Decl::Class(c),
+                    })));
+                    items.extend(stmt.map(ModuleItem::Stmt));
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    span,
+                    decl: DefaultDecl::Class(ClassExpr { ident: Some(ident), mut class }),
+                })) => {
+                    let stmt = self.wrap_static_prop_types(&ident, &mut class);
+                    items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                        span,
+                        decl: DefaultDecl::Class(ClassExpr {
+                            ident: Some(ident),
+                            class,
+                        }),
+                    })));
+                    items.extend(stmt.map(ModuleItem::Stmt));
+                }
+                item => items.push(item),
+            }
+        }
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.visit_mut_children_with(self);
+
+        for s in take(stmts) {
+            stmts.extend(self.replace_stmt(s));
+        }
+    }
+
+    fn visit_mut_class(&mut self, class: &mut Class) {
+        class.visit_mut_children_with(self);
+
+        // Named classes are wrapped by their statements.
+        if self.config.mode == Mode::Remove {
+            take_static_prop_types(class);
+        }
+    }
+}
+
+/// Whether `e` is `Component.propTypes = ...`.
+fn is_prop_types_assign(e: &Expr) -> bool {
+    matches!(
+        e,
+        Expr::Assign(AssignExpr {
+            op: op!("="),
+            left: AssignTarget::Simple(SimpleAssignTarget::Member(MemberExpr {
+                prop: MemberProp::Ident(prop),
+                ..
+            })),
+            ..
+        }) if prop.sym == "propTypes"
+    )
+}
+
+/// Removes `static propTypes = ...` from `class`, returning its value.
+fn take_static_prop_types(class: &mut Class) -> Option<Box<Expr>> {
+    let i = class.body.iter().position(|m| {
+        matches!(
+            m,
+            ClassMember::ClassProp(ClassProp {
+                is_static: true,
+                key: PropName::Ident(key),
+                ..
+            }) if key.sym == "propTypes"
+        )
+    })?;
+
+    match class.body.remove(i) {
+        ClassMember::ClassProp(mut p) => Some(p.value.take().unwrap_or_else(|| Expr::undefined(DUMMY_SP))),
+        _ => None,
+    }
+}
+
+/// Creates `if (process.env.NODE_ENV !== "production") { stmts }`.
+fn dev_only(stmts: Vec<Stmt>) -> Stmt {
+    let node_env = quote_ident!("process")
+        .make_member(quote_ident!("env"))
+        .make_member(quote_ident!("NODE_ENV"));
+
+    Stmt::If(IfStmt {
+        span: DUMMY_SP,
+        test: Box::new(Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            op: op!("!=="),
+            left: Box::new(node_env.into()),
+            right: Box::new(Expr::Lit(Lit::Str(atom!("production").into()))),
+        })),
+        cons: Box::new(Stmt::Block(BlockStmt {
+            stmts,
+            ..Default::default()
+        })),
+        alt: None,
+    })
+}
+
+/// Removes the imports of `prop-types` which are not used anymore.
+fn remove_unused_imports(m: &mut Module) {
+    let mut used = UsedIdents::default();
+    m.visit_with(&mut used);
+
+    m.body.retain_mut(|item| {
+        let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+            return true;
+        };
+        if import.src.value != "prop-types" || import.specifiers.is_empty() {
+            return true;
+        }
+
+        import.specifiers.retain(|s| {
+            let (ImportSpecifier::Named(ImportNamedSpecifier { local, .. })
+            | ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+            | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. })) = s;
+            used.0.contains(&local.to_id())
+        });
+        !import.specifiers.is_empty()
+    });
+}
+
+/// Collects the identifiers used outside of imports.
+#[derive(Default)]
+struct UsedIdents(AHashSet<Id>);
+
+impl Visit for UsedIdents {
+    noop_visit_type!();
+
+    fn visit_import_decl(&mut self, _: &ImportDecl) {}
+
+    fn visit_ident(&mut self, i: &Ident) {
+        self.0.insert(i.to_id());
+    }
+}
+
+/// Reports `msg` through the diagnostics handler of swc, or logs it if there
+/// is none.
+fn error(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.err(msg));
+    } else {
+        tracing::error!("{}", msg);
+    }
+}
+
+#[plugin_transform]
+pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    let Some(json) = _metadata.get_transform_plugin_config() else {
+        error("remove-prop-types: missing config");
+        return program;
+    };
+    // Reported rather than panicking, which would abort the whole compilation.
+    let config = match serde_json::from_str::<Config>(&json) {
+        Ok(config) => config,
+        Err(err) => {
+            error(&format!("remove-prop-types: invalid config `{}`: {}", json, err));
+            return program;
+        }
+    };
+
+    program.fold_with(&mut remove_prop_types(config))
+}
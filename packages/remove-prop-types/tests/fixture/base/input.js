@@ -0,0 +1,12 @@
+import PropTypes from 'prop-types';
+
+export function Button({ label }) {
+  return <button>{label}</button>;
+}
+
+Button.propTypes = {
+  label: PropTypes.string.isRequired,
+};
+Button.defaultProps = {
+  label: 'OK',
+};
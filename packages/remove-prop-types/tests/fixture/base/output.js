@@ -0,0 +1,6 @@
+export function Button({ label }) {
+    return <button>{label}</button>;
+}
+Button.defaultProps = {
+    label: 'OK'
+};
@@ -0,0 +1,15 @@
+import { string } from 'prop-types';
+import React from 'react';
+
+export default class Title extends React.Component {
+  static propTypes = { text: string };
+  static displayName = 'Title';
+
+  render() {
+    return <h1>{this.props.text}</h1>;
+  }
+}
+
+export const Sub = class extends React.Component {
+  static propTypes = { text: string };
+};
@@ -0,0 +1,9 @@
+import React from 'react';
+export default class Title extends React.Component {
+    static displayName = 'Title';
+    render() {
+        return <h1>{this.props.text}</h1>;
+    }
+}
+export const Sub = class extends React.Component {
+};
@@ -0,0 +1,7 @@
+import PropTypes, { checkPropTypes } from 'prop-types';
+
+export function validate(props) {
+  checkPropTypes({ id: PropTypes.number }, props, 'prop', 'validate');
+}
+
+validate.propTypes = { id: PropTypes.number };
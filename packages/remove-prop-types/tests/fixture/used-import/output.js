@@ -0,0 +1,6 @@
+import PropTypes, { checkPropTypes } from 'prop-types';
+export function validate(props) {
+    checkPropTypes({
+        id: PropTypes.number
+    }, props, 'prop', 'validate');
+}
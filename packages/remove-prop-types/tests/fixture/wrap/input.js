@@ -0,0 +1,17 @@
+import PropTypes from 'prop-types';
+import React from 'react';
+
+export class Title extends React.Component {
+  static propTypes = { text: PropTypes.string };
+
+  render() {
+    return <h1>{this.props.text}</h1>;
+  }
+}
+
+function Label({ text }) {
+  return <span>{text}</span>;
+}
+Label.propTypes = { text: PropTypes.string };
+
+export default Label;
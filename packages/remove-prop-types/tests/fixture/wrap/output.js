@@ -0,0 +1,21 @@
+import PropTypes from 'prop-types';
+import React from 'react';
+export class Title extends React.Component {
+    render() {
+        return <h1>{this.props.text}</h1>;
+    }
+}
+if (process.env.NODE_ENV !== "production") {
+    Title.propTypes = {
+        text: PropTypes.string
+    };
+}
+function Label({ text }) {
+    return <span>{text}</span>;
+}
+if (process.env.NODE_ENV !== "production") {
+    Label.propTypes = {
+        text: PropTypes.string
+    };
+}
+export default Label;
@@ -0,0 +1,169 @@
+use serde::Deserialize;
+use swc_common::util::take::Take;
+use swc_common::errors::HANDLER;
+use swc_core::ecma::{
+    ast::*,
+    visit::{as_folder, noop_visit_mut_type, noop_visit_type, Fold, FoldWith, Visit, VisitMut, VisitMutWith, VisitWith},
+};
+use swc_plugin_proxy::TransformPluginProgramMetadata;
+use swc_plugin_macro::plugin_transform;
+
+/// Options of strip-debugger.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Globals which are `false` outside of debug builds, like `__DEBUG__`,
+    /// whose `if (__DEBUG__) { ... }` and `__DEBUG__ && ...` statements are
+    /// removed too.
+    #[serde(default)]
+    pub guards: Vec<String>,
+}
+
+/// Removes `debugger;` statements, and the statements guarded by the
+/// [Config::guards].
+pub fn strip_debugger(config: Config) -> impl Fold {
+    as_folder(StripDebugger { config })
+}
+
+struct StripDebugger {
+    config: Config,
+}
+
+impl StripDebugger {
+    /// Returns the value of `test` if it is known from the guards.
+    fn eval(&self, test: &Expr) -> Option<bool> {
+        match test {
+            Expr::Ident(i) if self.config.guards.iter().any(|g| g == &*i.sym) => Some(false),
+            Expr::Unary(UnaryExpr { op: op!("!"), arg, .. }) => self.eval(arg).map(|v| !v),
+            Expr::Paren(ParenExpr { expr, .. }) => self.eval(expr),
+            // `__DEBUG__ && verbose`
+            Expr::Bin(BinExpr { op: op!("&&"), left, .. }) if self.eval(left) == Some(false) => Some(false),
+            Expr::Bin(BinExpr { op: op!("||"), left, .. }) if self.eval(left) == Some(true) => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl VisitMut for StripDebugger {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.visit_mut_children_with(self);
+        items.retain(|i| !matches!(i, ModuleItem::Stmt(Stmt::Empty(..))));
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.visit_mut_children_with(self);
+        stmts.retain(|s| !matches!(s, Stmt::Empty(..)));
+    }
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        s.visit_mut_children_with(self);
+
+        match s {
+            Stmt::Debugger(DebuggerStmt { span }) => {
+                *s = Stmt::Empty(EmptyStmt { span: *span });
+            }
+            Stmt::If(IfStmt { span, test, cons, alt }) => match self.eval(test) {
+                Some(true) => *s = *cons.take(),
+                Some(false) => match alt.take() {
+                    Some(alt) => *s = *alt,
+                    None => *s = Stmt::Empty(EmptyStmt { span: *span }),
+                },
+                // `if (x) debugger;`
+                None if alt.is_none() && is_empty(cons) => {
+                    *s = if has_side_effects(test) {
+                        Stmt::Expr(ExprStmt { span: *span, expr: test.take() })
+                    } else {
+                        Stmt::Empty(EmptyStmt { span: *span })
+                    };
+                }
+                None => {}
+            },
+            // `__DEBUG__ && log(state);`
+            Stmt::Expr(ExprStmt { span, expr }) => {
+                if let Expr::Bin(BinExpr { op: op!("&&"), left, .. }) = &**expr {
+                    if self.eval(left) == Some(false) {
+                        *s = Stmt::Empty(EmptyStmt { span: *span });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Whether `s` does nothing, like `;` or `{}`.
+fn is_empty(s: &Stmt) -> bool {
+    match s {
+        Stmt::Empty(..) => true,
+        Stmt::Block(BlockStmt { stmts, .. }) => stmts.iter().all(is_empty),
+        _ => false,
+    }
+}
+
+/// Whether evaluating `e` may have side effects, like calls. Functions are not
+/// looked into, as they are not called by their definition.
+fn has_side_effects(e: &Expr) -> bool {
+    let mut v = SideEffects::default();
+    e.visit_with(&mut v);
+    v.found
+}
+
+#[derive(Default)]
+struct SideEffects {
+    found: bool,
+}
+
+impl Visit for SideEffects {
+    noop_visit_type!();
+
+    fn visit_expr(&mut self, e: &Expr) {
+        match e {
+            Expr::Call(..)
+            | Expr::New(..)
+            | Expr::Await(..)
+            | Expr::Yield(..)
+            | Expr::Update(..)
+            | Expr::Assign(..)
+            | Expr::TaggedTpl(..)
+            | Expr::Unary(UnaryExpr { op: op!("delete"), .. }) => self.found = true,
+            Expr::OptChain(c) if c.base.is_call() => self.found = true,
+            _ => e.visit_children_with(self),
+        }
+    }
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_class(&mut self, _: &Class) {}
+}
+
+/// Reports `msg` through the diagnostics handler of swc, or logs it if there
+/// is none.
+fn error(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.err(msg));
+    } else {
+        tracing::error!("{}", msg);
+    }
+}
+
+#[plugin_transform]
+pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    let Some(json) = _metadata.get_transform_plugin_config() else {
+        error("strip-debugger: missing config");
+        return program;
+    };
+    // Reported rather than panicking, which would abort the whole compilation.
+    let config = match serde_json::from_str::<Config>(&json) {
+        Ok(config) => config,
+        Err(err) => {
+            error(&format!("strip-debugger: invalid config `{}`: {}", json, err));
+            return program;
+        }
+    };
+
+    program.fold_with(&mut strip_debugger(config))
+}
@@ -0,0 +1,11 @@
+debugger;
+
+export function render(props) {
+  debugger;
+  if (props.broken) debugger;
+  if (props.verbose) {
+    debugger;
+  }
+  if (check(props)) debugger;
+  return props.children;
+}
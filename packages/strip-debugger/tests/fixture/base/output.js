@@ -0,0 +1,4 @@
+export function render(props) {
+    check(props);
+    return props.children;
+}
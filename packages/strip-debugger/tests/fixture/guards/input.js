@@ -0,0 +1,14 @@
+if (__DEBUG__) {
+  installDevtools();
+}
+
+export function update(state) {
+  __DEBUG__ && console.log(state);
+  if (__DEBUG__) validate(state);
+  else state.checked = false;
+  if (!__DEBUG__) {
+    freeze(state);
+  }
+  if (__DEBUG__ && state.verbose) dump(state);
+  return state;
+}
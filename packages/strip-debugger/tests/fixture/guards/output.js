@@ -0,0 +1,7 @@
+export function update(state) {
+    state.checked = false;
+    {
+        freeze(state);
+    }
+    return state;
+}
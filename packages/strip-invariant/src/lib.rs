@@ -0,0 +1,357 @@
+use serde::Deserialize;
+use std::mem::take;
+use swc_common::{
+    collections::{AHashMap, AHashSet},
+    util::take::Take,
+    DUMMY_SP,
+};
+use swc_common::errors::HANDLER;
+use swc_core::ecma::{
+    ast::*,
+    atoms::atom,
+    utils::{quote_ident, ExprFactory, StmtLike},
+    visit::{as_folder, noop_visit_mut_type, noop_visit_type, Fold, FoldWith, Visit, VisitMut, VisitMutWith, VisitWith},
+};
+use swc_plugin_proxy::TransformPluginProgramMetadata;
+use swc_plugin_macro::plugin_transform;
+
+/// Options of strip-invariant.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    #[serde(default)]
+    pub mode: Mode,
+
+    /// Keep the calls of `warning`, which are removed otherwise.
+    #[serde(default)]
+    pub keep_warnings: bool,
+}
+
+/// What becomes of the calls of `invariant`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Mode {
+    /// `invariant(cond, "message", x)` becomes `invariant(cond)`.
+    #[default]
+    DropMessage,
+    /// `invariant(cond, "message")` becomes
+    /// `if (!cond) throw new Error("Invariant failed")`, so `invariant` needn't
+    /// be bundled.
+    Throw,
+    /// `invariant(cond, "message")` is removed, conditions and all.
+    Remove,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Invariant,
+    Warning,
+}
+
+/// Strips the calls of the default exports of `invariant`, `tiny-invariant`,
+/// `warning` and `tiny-warning` for production builds, removing the imports
+/// left unused.
+pub fn strip_invariant(config: Config) -> impl Fold {
+    as_folder(StripInvariant {
+        config,
+        imports: Default::default(),
+    })
+}
+
+struct StripInvariant {
+    config: Config,
+    /// Default imports of the packages, by kind.
+    imports: AHashMap<Id, Kind>,
+}
+
+impl StripInvariant {
+    /// Returns the kind of the function called by `e`.
+    fn kind(&self, e: &Expr) -> Option<Kind> {
+        let Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            ..
+        }) = e
+        else {
+            return None;
+        };
+        match &**callee {
+            Expr::Ident(i) => self.imports.get(&i.to_id()).copied(),
+            _ => None,
+        }
+    }
+
+    /// Returns the statement replacing the call `e` used as a statement, or
+    /// `None` to remove it.
+    fn replace_stmt(&self, e: &mut Expr) -> Option<Stmt> {
+        match self.kind(e)? {
+            Kind::Warning if !self.config.keep_warnings => None,
+            Kind::Invariant if self.config.mode == Mode::Remove => None,
+            Kind::Invariant if self.config.mode == Mode::Throw => {
+                let Expr::Call(CallExpr { args, .. }) = e else {
+                    unreachable!()
+                };
+                let test = match args.first_mut() {
+                    Some(ExprOrSpread { spread: None, expr }) => negate(expr.take()),
+                    // Fails whatever the condition.
+                    _ => Expr::Lit(Lit::Bool(true.into())).into(),
+                };
+                let error = NewExpr {
+                    span: DUMMY_SP,
+                    callee: Box::new(quote_ident!("Error").into()),
+                    args: Some(vec![Expr::Lit(Lit::Str(atom!("Invariant failed").into())).as_arg()]),
+                    ..Take::dummy()
+                };
+                Some(Stmt::If(IfStmt {
+                    span: DUMMY_SP,
+                    test,
+                    cons: Box::new(Stmt::Throw(ThrowStmt {
+                        span: DUMMY_SP,
+                        arg: Box::new(error.into()),
+                    })),
+                    alt: None,
+                }))
+            }
+            _ => {
+                self.strip_expr(e);
+                Some(e.take().into_stmt())
+            }
+        }
+    }
+
+    /// Strips the call `e` used as a value.
+    fn strip_expr(&self, e: &mut Expr) {
+        let Some(kind) = self.kind(e) else {
+            return;
+        };
+        let Expr::Call(CallExpr { args, .. }) = e else {
+            unreachable!()
+        };
+
+        match kind {
+            Kind::Warning if !self.config.keep_warnings => *e = *Expr::undefined(DUMMY_SP),
+            Kind::Warning => {}
+            Kind::Invariant if self.config.mode == Mode::Remove => *e = *Expr::undefined(DUMMY_SP),
+            // Throwing needs a statement.
+            Kind::Invariant => args.truncate(1),
+        }
+    }
+
+    fn strip_stmts<T: StmtLike + VisitMutWith<Self>>(&mut self, stmts: &mut Vec<T>) {
+        for mut item in take(stmts) {
+            match item.as_stmt_mut() {
+                Some(Stmt::Expr(ExprStmt { expr, .. })) if self.kind(expr).is_some() => {
+                    if let Expr::Call(call) = &mut **expr {
+                        call.args.visit_mut_with(self);
+                    }
+                    stmts.extend(self.replace_stmt(expr).map(T::from));
+                }
+                _ => {
+                    let is_if = matches!(item.as_stmt(), Some(Stmt::If(..)));
+                    item.visit_mut_with(self);
+                    // Dropped if it was left empty, like `if (x) invariant(...)`.
+                    if !(is_if && matches!(item.as_stmt(), Some(Stmt::Empty(..)))) {
+                        stmts.push(item);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl VisitMut for StripInvariant {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        for item in &m.body {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+                continue;
+            };
+            let kind = match &*import.src.value {
+                "invariant" | "tiny-invariant" => Kind::Invariant,
+                "warning" | "tiny-warning" => Kind::Warning,
+                _ => continue,
+            };
+            for s in &import.specifiers {
+                if let ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) = s {
+                    self.imports.insert(local.to_id(), kind);
+                }
+            }
+        }
+        if self.imports.is_empty() {
+            return;
+        }
+
+        m.visit_mut_children_with(self);
+        remove_unused_imports(m);
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        self.strip_stmts(items);
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        self.strip_stmts(stmts);
+    }
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        s.visit_mut_children_with(self);
+
+        // Bodies of `if` and loops without blocks.
+        if let Stmt::Expr(ExprStmt { expr, .. }) = s {
+            if self.kind(expr).is_some() {
+                *s = self.replace_stmt(expr).unwrap_or(Stmt::Empty(EmptyStmt { span: DUMMY_SP }));
+            }
+        }
+
+        if let Stmt::If(IfStmt { span, test, cons, alt: None }) = s {
+            if is_empty(cons) {
+                *s = if has_side_effects(test) {
+                    Stmt::Expr(ExprStmt { span: *span, expr: test.take() })
+                } else {
+                    Stmt::Empty(EmptyStmt { span: *span })
+                };
+            }
+        }
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+        self.strip_expr(e);
+    }
+
+    fn visit_mut_expr_stmt(&mut self, s: &mut ExprStmt) {
+        // Left for the statements to replace.
+        if self.kind(&s.expr).is_none() {
+            s.visit_mut_children_with(self);
+        } else if let Expr::Call(call) = &mut *s.expr {
+            call.args.visit_mut_with(self);
+        }
+    }
+}
+
+/// Creates `!e`, wrapped in parentheses unless `e` binds tighter.
+fn negate(e: Box<Expr>) -> Box<Expr> {
+    let arg = match &*e {
+        Expr::Ident(..) | Expr::Member(..) | Expr::Call(..) | Expr::Lit(..) | Expr::Unary(..) | Expr::Paren(..) => e,
+        _ => Box::new(Expr::Paren(ParenExpr { span: DUMMY_SP, expr: e })),
+    };
+    Box::new(Expr::Unary(UnaryExpr {
+        span: DUMMY_SP,
+        op: op!("!"),
+        arg,
+    }))
+}
+
+/// Whether `s` does nothing, like `;` or `{}`.
+fn is_empty(s: &Stmt) -> bool {
+    match s {
+        Stmt::Empty(..) => true,
+        Stmt::Block(BlockStmt { stmts, .. }) => stmts.iter().all(is_empty),
+        _ => false,
+    }
+}
+
+/// Whether evaluating `e` may have side effects, like calls. Functions are not
+/// looked into, as they are not called by their definition.
+fn has_side_effects(e: &Expr) -> bool {
+    let mut v = SideEffects::default();
+    e.visit_with(&mut v);
+    v.found
+}
+
+#[derive(Default)]
+struct SideEffects {
+    found: bool,
+}
+
+impl Visit for SideEffects {
+    noop_visit_type!();
+
+    fn visit_expr(&mut self, e: &Expr) {
+        match e {
+            Expr::Call(..)
+            | Expr::New(..)
+            | Expr::Await(..)
+            | Expr::Yield(..)
+            | Expr::Update(..)
+            | Expr::Assign(..)
+            | Expr::TaggedTpl(..)
+            | Expr::Unary(UnaryExpr { op: op!("delete"), .. }) => self.found = true,
+            Expr::OptChain(c) if c.base.is_call() => self.found = true,
+            _ => e.visit_children_with(self),
+        }
+    }
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_class(&mut self, _: &Class) {}
+}
+
+/// Removes the imports of the packages which are not used anymore.
+fn remove_unused_imports(m: &mut Module) {
+    let mut used = UsedIdents::default();
+    m.visit_with(&mut used);
+
+    m.body.retain_mut(|item| {
+        let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+            return true;
+        };
+        if !matches!(&*import.src.value, "invariant" | "tiny-invariant" | "warning" | "tiny-warning")
+            || import.specifiers.is_empty()
+        {
+            return true;
+        }
+
+        import.specifiers.retain(|s| {
+            let (ImportSpecifier::Named(ImportNamedSpecifier { local, .. })
+            | ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+            | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. })) = s;
+            used.0.contains(&local.to_id())
+        });
+        !import.specifiers.is_empty()
+    });
+}
+
+/// Collects the identifiers used outside of imports.
+#[derive(Default)]
+struct UsedIdents(AHashSet<Id>);
+
+impl Visit for UsedIdents {
+    noop_visit_type!();
+
+    fn visit_import_decl(&mut self, _: &ImportDecl) {}
+
+    fn visit_ident(&mut self, i: &Ident) {
+        self.0.insert(i.to_id());
+    }
+}
+
+/// Reports `msg` through the diagnostics handler of swc, or logs it if there
+/// is none.
+fn error(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.err(msg));
+    } else {
+        tracing::error!("{}", msg);
+    }
+}
+
+#[plugin_transform]
+pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    let Some(json) = _metadata.get_transform_plugin_config() else {
+        error("strip-invariant: missing config");
+        return program;
+    };
+    // Reported rather than panicking, which would abort the whole compilation.
+    let config = match serde_json::from_str::<Config>(&json) {
+        Ok(config) => config,
+        Err(err) => {
+            error(&format!("strip-invariant: invalid config `{}`: {}", json, err));
+            return program;
+        }
+    };
+
+    program.fold_with(&mut strip_invariant(config))
+}
@@ -0,0 +1,8 @@
+import invariant from 'tiny-invariant';
+export function get(users, id) {
+    invariant(users);
+    invariant(id > 0 && id < users.length);
+    if (id === 0) invariant(false);
+    const checked = invariant(users[id]);
+    return users[id];
+}
@@ -0,0 +1,10 @@
+import invariant from 'tiny-invariant';
+import warning from 'warning';
+export function get(users, id) {
+    invariant(users);
+    invariant(id > 0 && id < users.length);
+    warning(users.length < 1000, 'many users: %s', users.length);
+    if (id === 0) invariant(false);
+    const checked = invariant(users[id]);
+    return users[id];
+}
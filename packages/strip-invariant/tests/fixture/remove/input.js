@@ -0,0 +1,14 @@
+import invariant from 'tiny-invariant';
+import warning from 'warning';
+
+export function get(users, id) {
+  invariant(users, 'users are required');
+  invariant(id > 0 && id < users.length, `invalid id ${id}`);
+  warning(users.length < 1000, 'many users: %s', users.length);
+  if (id === 0) invariant(false, 'unreachable');
+  if (lookup(id)) {
+    invariant(users[id], 'missing user');
+  }
+  const checked = invariant(users[id]);
+  return users[id];
+}
@@ -0,0 +1,5 @@
+export function get(users, id) {
+    lookup(id);
+    const checked = void 0;
+    return users[id];
+}
@@ -0,0 +1,10 @@
+import invariant from 'tiny-invariant';
+export function get(users, id) {
+    if (!users) throw new Error("Invariant failed");
+    if (!(id > 0 && id < users.length)) throw new Error("Invariant failed");
+    if (id === 0) {
+        if (!false) throw new Error("Invariant failed");
+    }
+    const checked = invariant(users[id]);
+    return users[id];
+}
@@ -0,0 +1,297 @@
+use serde::Deserialize;
+use std::path::Path;
+use swc_common::{
+    collections::AHashSet, plugin::metadata::TransformPluginMetadataContextKind, util::take::Take, DUMMY_SP,
+};
+use swc_common::errors::HANDLER;
+use swc_core::ecma::{
+    ast::*,
+    atoms::Atom,
+    utils::{quote_ident, ExprFactory},
+    visit::{as_folder, noop_visit_mut_type, Fold, FoldWith, VisitMut, VisitMutWith},
+};
+use swc_plugin_proxy::TransformPluginProgramMetadata;
+use swc_plugin_macro::plugin_transform;
+
+/// Options of styled-components, like those of `babel-plugin-styled-components`.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Give each component a `componentId` stable across builds, so the
+    /// class names rendered on the server match those of the client. Defaults
+    /// to `true`.
+    #[serde(default)]
+    pub ssr: Option<bool>,
+
+    /// Give each component the `displayName` of its binding, like `Button`.
+    /// Defaults to `true`.
+    #[serde(default)]
+    pub display_name: Option<bool>,
+
+    /// Prefix the `displayName` with the name of the file, like
+    /// `Form__Button`, or of its directory for `index` files. Defaults to
+    /// `true`.
+    #[serde(default)]
+    pub file_name: Option<bool>,
+
+    /// Prefix of the `componentId`s, like `app` for `app__sc-1b2c3d-0`.
+    #[serde(default)]
+    pub namespace: Option<String>,
+}
+
+/// Adds `.withConfig({ displayName, componentId })` to the `styled` components
+/// of the file named `file_name`.
+pub fn styled_components(config: Config, file_name: &str) -> impl Fold {
+    let path = Path::new(file_name);
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+    let block_name = match stem {
+        "index" => path
+            .parent()
+            .and_then(|p| p.file_name())
+            .and_then(|s| s.to_str())
+            .unwrap_or(stem),
+        _ => stem,
+    };
+
+    as_folder(StyledComponents {
+        block_name: prefix_leading_digit(block_name),
+        file_hash: base36(fnv1a(file_name.as_bytes())),
+        config,
+        styled: Default::default(),
+        name: None,
+        next_id: 0,
+    })
+}
+
+struct StyledComponents {
+    config: Config,
+    block_name: String,
+    file_hash: String,
+    /// Default imports of `styled-components`.
+    styled: AHashSet<Id>,
+    /// Name of the binding being initialized.
+    name: Option<Atom>,
+    next_id: usize,
+}
+
+impl StyledComponents {
+    /// Whether `e` is a styled component factory, like `styled.div`,
+    /// `styled(Link)` or `styled.div.attrs(...)`.
+    fn is_styled(&self, e: &Expr) -> bool {
+        match e {
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(..),
+                ..
+            }) => matches!(&**obj, Expr::Ident(i) if self.styled.contains(&i.to_id())),
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                ..
+            }) => match &**callee {
+                Expr::Ident(i) => self.styled.contains(&i.to_id()),
+                Expr::Member(MemberExpr {
+                    obj,
+                    prop: MemberProp::Ident(prop),
+                    ..
+                }) => (prop.sym == "attrs" || prop.sym == "withConfig") && self.is_styled(obj),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Whether the factory `e` is already configured.
+    fn has_config(e: &Expr) -> bool {
+        match e {
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                ..
+            }) => match &**callee {
+                Expr::Member(MemberExpr {
+                    obj,
+                    prop: MemberProp::Ident(prop),
+                    ..
+                }) => prop.sym == "withConfig" || Self::has_config(obj),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Adds `.withConfig(...)` to the factory `e`.
+    fn configure(&mut self, e: &mut Box<Expr>) {
+        if !self.is_styled(e) || Self::has_config(e) {
+            return;
+        }
+
+        let mut props = vec![];
+        if self.config.display_name.unwrap_or(true) {
+            let display_name = match (&self.name, self.config.file_name.unwrap_or(true)) {
+                (Some(name), true) if **name != *self.block_name => format!("{}__{}", self.block_name, name),
+                (Some(name), _) => name.to_string(),
+                (None, true) => self.block_name.clone(),
+                (None, false) => String::new(),
+            };
+            if !display_name.is_empty() {
+                props.push(str_prop("displayName", display_name));
+            }
+        }
+        if self.config.ssr.unwrap_or(true) {
+            let namespace = match &self.config.namespace {
+                Some(namespace) => format!("{}__", namespace),
+                None => String::new(),
+            };
+            props.push(str_prop("componentId", format!("{}sc-{}-{}", namespace, self.file_hash, self.next_id)));
+            self.next_id += 1;
+        }
+        if props.is_empty() {
+            return;
+        }
+
+        let factory = e.take();
+        *e = Box::new(
+            factory
+                .make_member(quote_ident!("withConfig"))
+                .as_call(DUMMY_SP, vec![ObjectLit { span: DUMMY_SP, props }.as_arg()]),
+        );
+    }
+}
+
+impl VisitMut for StyledComponents {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        for item in &m.body {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+                continue;
+            };
+            if !import.src.value.starts_with("styled-components") {
+                continue;
+            }
+            for s in &import.specifiers {
+                if let ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) = s {
+                    self.styled.insert(local.to_id());
+                }
+            }
+        }
+
+        if !self.styled.is_empty() {
+            m.visit_mut_children_with(self);
+        }
+    }
+
+    fn visit_mut_var_declarator(&mut self, d: &mut VarDeclarator) {
+        let old = self.name.take();
+        if let Pat::Ident(name) = &d.name {
+            self.name = Some(name.sym.clone());
+        }
+        d.visit_mut_children_with(self);
+        self.name = old;
+    }
+
+    fn visit_mut_key_value_prop(&mut self, p: &mut KeyValueProp) {
+        let old = self.name.take();
+        if let PropName::Ident(key) = &p.key {
+            self.name = Some(key.sym.clone());
+        }
+        p.visit_mut_children_with(self);
+        self.name = old;
+    }
+
+    fn visit_mut_function(&mut self, f: &mut Function) {
+        let old = self.name.take();
+        f.visit_mut_children_with(self);
+        self.name = old;
+    }
+
+    fn visit_mut_arrow_expr(&mut self, f: &mut ArrowExpr) {
+        let old = self.name.take();
+        f.visit_mut_children_with(self);
+        self.name = old;
+    }
+
+    // styled.div`color: red;`
+    fn visit_mut_tagged_tpl(&mut self, t: &mut TaggedTpl) {
+        t.visit_mut_children_with(self);
+        self.configure(&mut t.tag);
+    }
+
+    // styled.div({ color: 'red' })
+    fn visit_mut_call_expr(&mut self, c: &mut CallExpr) {
+        c.visit_mut_children_with(self);
+
+        if let Callee::Expr(callee) = &mut c.callee {
+            // The arguments of `styled(Link)` are not styles.
+            let is_factory_call = matches!(&**callee, Expr::Ident(..))
+                || matches!(&**callee, Expr::Member(MemberExpr { prop: MemberProp::Ident(p), .. }) if p.sym == "attrs" || p.sym == "withConfig");
+            if !is_factory_call {
+                self.configure(callee);
+            }
+        }
+    }
+}
+
+fn str_prop(key: &str, value: String) -> PropOrSpread {
+    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+        key: PropName::Ident(quote_ident!(key)),
+        value: Box::new(Expr::Lit(Lit::Str(value.into()))),
+    })))
+}
+
+/// Prefixes `name` with `_` if it starts with a digit, which class names can't.
+fn prefix_leading_digit(name: &str) -> String {
+    match name.starts_with(|c: char| c.is_ascii_digit()) {
+        true => format!("_{}", name),
+        false => name.to_string(),
+    }
+}
+
+/// The 32-bit FNV-1a hash of `bytes`.
+fn fnv1a(bytes: &[u8]) -> u32 {
+    bytes
+        .iter()
+        .fold(0x811c9dc5, |hash, b| (hash ^ u32::from(*b)).wrapping_mul(0x01000193))
+}
+
+fn base36(mut n: u32) -> String {
+    let mut digits = vec![];
+    loop {
+        digits.push(std::char::from_digit(n % 36, 36).unwrap());
+        n /= 36;
+        if n == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+/// Reports `msg` through the diagnostics handler of swc, or logs it if there
+/// is none.
+fn error(msg: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.err(msg));
+    } else {
+        tracing::error!("{}", msg);
+    }
+}
+
+#[plugin_transform]
+pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    let Some(json) = _metadata.get_transform_plugin_config() else {
+        error("styled-components: missing config");
+        return program;
+    };
+    // Reported rather than panicking, which would abort the whole compilation.
+    let config = match serde_json::from_str::<Config>(&json) {
+        Ok(config) => config,
+        Err(err) => {
+            error(&format!("styled-components: invalid config `{}`: {}", json, err));
+            return program;
+        }
+    };
+    let file_name = _metadata
+        .get_context(&TransformPluginMetadataContextKind::Filename)
+        .unwrap_or_default();
+
+    program.fold_with(&mut styled_components(config, &file_name))
+}
@@ -0,0 +1,15 @@
+import styled from 'styled-components';
+import { Link } from './link';
+
+export const Button = styled.button`
+  color: ${(props) => props.color};
+`;
+const Anchor = styled(Link).attrs({ role: 'link' })`
+  text-decoration: none;
+`;
+export const Form = styled.form({ display: 'flex' });
+const Configured = styled.div.withConfig({ componentId: 'custom' })``;
+export const theme = {
+  Title: styled.h1``,
+};
+export default styled.section``;
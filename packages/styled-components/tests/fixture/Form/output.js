@@ -0,0 +1,35 @@
+import styled from 'styled-components';
+import { Link } from './link';
+export const Button = styled.button.withConfig({
+    displayName: "Form__Button",
+    componentId: "sc-d88i3k-0"
+})`
+  color: ${(props)=>props.color};
+`;
+const Anchor = styled(Link).attrs({
+    role: 'link'
+}).withConfig({
+    displayName: "Form__Anchor",
+    componentId: "sc-d88i3k-1"
+})`
+  text-decoration: none;
+`;
+export const Form = styled.form.withConfig({
+    displayName: "Form",
+    componentId: "sc-d88i3k-2"
+})({
+    display: 'flex'
+});
+const Configured = styled.div.withConfig({
+    componentId: 'custom'
+})``;
+export const theme = {
+    Title: styled.h1.withConfig({
+        displayName: "Form__Title",
+        componentId: "sc-d88i3k-3"
+    })``
+};
+export default styled.section.withConfig({
+    displayName: "Form",
+    componentId: "sc-d88i3k-4"
+})``;
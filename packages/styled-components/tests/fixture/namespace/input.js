@@ -0,0 +1,3 @@
+import styled from 'styled-components/native';
+
+export const Button = styled.Text``;
@@ -0,0 +1,4 @@
+import styled from 'styled-components/native';
+export const Button = styled.Text.withConfig({
+    componentId: "app__sc-9ipdhb-0"
+})``;
@@ -0,0 +1,4 @@
+import styled from 'styled-components';
+
+export const Button = styled.button``;
+export default styled.section``;
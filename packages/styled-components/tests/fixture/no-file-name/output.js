@@ -0,0 +1,8 @@
+import styled from 'styled-components';
+export const Button = styled.button.withConfig({
+    displayName: "Button",
+    componentId: "sc-1ro0dnu-0"
+})``;
+export default styled.section.withConfig({
+    componentId: "sc-1ro0dnu-1"
+})``;
@@ -0,0 +1,2 @@
+import styled from '@emotion/styled';
+export const Button = styled.button``;